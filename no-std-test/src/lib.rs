@@ -0,0 +1,40 @@
+//! A `no_std` crate that uses `vale`, to check that neither the library nor the code generated by
+//! its macros depend on `std`.
+#![no_std]
+
+extern crate alloc;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A sensor whose configuration is validated by the derive.
+#[derive(vale::Validate)]
+pub struct Sensor {
+    /// The name of the sensor.
+    #[validate(trim, len_range(1, 16), to_lower_case)]
+    pub name: String,
+    /// The temperature, in degrees Celsius.
+    #[validate(gte(-40), lte(125))]
+    pub temperature: i32,
+    /// The most recent readings.
+    #[validate(max_items(4), each(gt(0)))]
+    pub readings: Vec<u32>,
+    /// The address the sensor reports to.
+    #[validate(ip)]
+    pub address: String,
+}
+
+/// A unit of measurement, validated by the derive on an enum.
+#[derive(vale::Validate)]
+pub enum Unit {
+    /// A unit with a name.
+    Named(#[validate(not_empty)] String),
+    /// A unit without a name.
+    Unnamed,
+}
+
+// checks that two sensors don't share a name, using `ruleset` and `rule`
+#[vale::ruleset]
+pub fn distinct(a: &Sensor, b: &Sensor) -> vale::Result {
+    vale::rule!(a.name != b.name, "sensors share the name `{}`", a.name);
+}
@@ -0,0 +1,48 @@
+use vale::Validate;
+use vale_no_std_test::{distinct, Sensor, Unit};
+
+fn valid_sensor() -> Sensor {
+    Sensor {
+        name: "  Kitchen ".to_string(),
+        temperature: 21,
+        readings: vec![20, 21],
+        address: "10.0.0.1".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut sensor = valid_sensor();
+    sensor.validate().unwrap();
+    assert_eq!(sensor.name, "kitchen");
+    Unit::Named("celsius".to_string()).validate().unwrap();
+    Unit::Unnamed.validate().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    let mut sensor = Sensor {
+        name: " ".to_string(),
+        temperature: 200,
+        readings: vec![1, 0],
+        address: "localhost".to_string(),
+    };
+    assert_eq!(
+        sensor.validate(),
+        Err(vec![
+            "Failed to validate field `name`, length out of range".to_string(),
+            "Failed to validate field `temperature`, value must be at most 125".to_string(),
+            "Failed to validate field `readings[1]`, value too low".to_string(),
+            "Failed to validate field `address`, not a valid IPv4 or IPv6 address".to_string(),
+        ]),
+    );
+    assert!(Unit::Named(String::new()).validate().is_err());
+}
+
+#[test]
+fn test_ruleset() {
+    assert_eq!(
+        distinct(&valid_sensor(), &valid_sensor()),
+        Err(vec!["sensors share the name `  Kitchen `".to_string()]),
+    );
+}
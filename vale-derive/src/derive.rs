@@ -1,231 +1,1941 @@
-use syn::parse;
+use syn::{parse, punctuated as punct, spanned::Spanned, token};
 use quote::ToTokens;
 
 pub(crate) struct Validate {
     name: syn::Ident,
-    validations: Vec<FieldValidation>,
+    generics: syn::Generics,
+    /// The validations placed on the struct or enum itself, which operate on the entire entity.
+    conditions: Vec<Condition>,
+    data: Data,
+    /// The path to the `vale` crate, from `crate = path`, for when the dependency is renamed.
+    krate: Option<syn::Path>,
+    /// The number of errors after which validation stops, from `max_errors = n`.
+    max_errors: Option<syn::LitInt>,
+}
+
+/// The validations of either a struct, which are listed per field, or an enum, which are listed per
+/// field per variant.
+enum Data {
+    Struct(Vec<FieldValidation>),
+    Enum(Vec<VariantValidation>),
 }
 
 impl parse::Parse for Validate {
     fn parse(input: parse::ParseStream) -> parse::Result<Self> {
-        let span = proc_macro2::Span::call_site();
         let derive_input = syn::DeriveInput::parse(input)?;
-        let data = match derive_input.data {
-            syn::Data::Struct(data) => data,
-            syn::Data::Enum(_) | syn::Data::Union(_) => {
-                return Err(parse::Error::new(span, "enums and unions are not supported"));
+        let span = derive_input.ident.span();
+        let mut conditions = Vec::new();
+        let mut krate = None;
+        let mut max_errors = None;
+        for attr in derive_input.attrs {
+            if attr.path.is_ident("validate") {
+                let attr = Condition::parse(attr)?;
+                if let Some(lit) = attr.rename {
+                    return Err(parse::Error::new(lit.span(), "`rename` can only be used on fields"));
+                }
+                if let Some(path) = attr.krate {
+                    if krate.is_some() {
+                        return Err(parse::Error::new_spanned(path, "`crate` can only be used once"));
+                    }
+                    krate = Some(path);
+                }
+                if let Some(lit) = attr.max_errors {
+                    if max_errors.is_some() {
+                        let msg = "`max_errors` can only be used once";
+                        return Err(parse::Error::new(lit.span(), msg));
+                    }
+                    max_errors = Some(lit);
+                }
+                conditions.extend(attr.conditions);
+            }
+        }
+        let options = Options {
+            show_values: Options::take_flag(&mut conditions, "show_values"),
+            transform_first: Options::take_flag(&mut conditions, "transform_first"),
+        };
+        let mut data = match derive_input.data {
+            syn::Data::Struct(data) => Data::Struct(Self::parse_struct(data, span)?),
+            syn::Data::Enum(data) => Data::Enum(Self::parse_enum(data)?),
+            syn::Data::Union(data) => {
+                return Err(parse::Error::new(data.union_token.span, "unions are not supported"));
             },
         };
+        let fields: Vec<&mut FieldValidation> = match &mut data {
+            Data::Struct(validations) => validations.iter_mut().collect(),
+            Data::Enum(variants) => variants.iter_mut().flat_map(|v| &mut v.validations).collect(),
+        };
+        for field in fields {
+            field.options = options;
+        }
+        Ok(Self {
+            name: derive_input.ident,
+            generics: derive_input.generics,
+            conditions,
+            data,
+            krate,
+            max_errors,
+        })
+    }
+}
+
+impl Validate {
+    fn parse_struct(data: syn::DataStruct, span: proc_macro2::Span) -> parse::Result<Vec<FieldValidation>> {
         let fields = match data.fields {
             syn::Fields::Named(fields) => fields,
-            syn::Fields::Unnamed(_) => {
-                return Err(parse::Error::new(span, "can't validate a tuple struct"));
+            syn::Fields::Unnamed(fields) => {
+                return Err(parse::Error::new_spanned(fields, "can't validate a tuple struct"));
             }
             syn::Fields::Unit => {
                 return Err(parse::Error::new(span, "can't validate a unit struct"));
             }
         }.named;
         let mut validations = Vec::new();
-        for field in fields.into_iter() {
-            validations.push(FieldValidation::parse(field)?);
+        for (idx, field) in fields.into_iter().enumerate() {
+            validations.push(FieldValidation::parse(idx, field)?);
         }
-        Ok(Self { name: derive_input.ident, validations })
+        Ok(validations)
+    }
+
+    fn parse_enum(data: syn::DataEnum) -> parse::Result<Vec<VariantValidation>> {
+        let mut variants = Vec::new();
+        for variant in data.variants.into_iter() {
+            let mut validations = Vec::new();
+            for (idx, field) in variant.fields.into_iter().enumerate() {
+                let validation = FieldValidation::parse(idx, field)?;
+                for condition in &validation.conditions {
+                    if condition.kind()?.uses_sibling() {
+                        let span = condition.name.span();
+                        let msg = format!("`{}` can only be used on the fields of a struct", condition.name);
+                        return Err(parse::Error::new(span, msg));
+                    }
+                }
+                validations.push(validation);
+            }
+            variants.push(VariantValidation { name: variant.ident, validations });
+        }
+        Ok(variants)
     }
-}
 
-impl Validate {
     pub(crate) fn finish(self) -> proc_macro2::TokenStream {
-        let name = self.name;
-        let conditions: Vec<proc_macro2::TokenStream> = self
-            .validations
-            .iter()
-            .flat_map(move |FieldValidation { name, conditions }| {
-                conditions.iter().map(move |c| (c, name))
-            })
-            .map(|(c, name)| c.finish(name).unwrap())
-            .collect();
+        let name = &self.name;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let body = match self.finish_body(Mode { mutable: true, keyed: false }) {
+            Ok(body) => body,
+            Err(e) => return e.to_compile_error(),
+        };
+        let keyed_body = match self.finish_body(Mode { mutable: true, keyed: true }) {
+            Ok(body) => body,
+            Err(e) => return e.to_compile_error(),
+        };
+        let ruleset = match &self.max_errors {
+            Some(max) => quote::quote! { #[vale::ruleset(max_errors = #max)] },
+            None => quote::quote! { #[vale::ruleset] },
+        };
+        let validate_ref = match self.is_mutating() {
+            Ok(true) => proc_macro2::TokenStream::new(),
+            Ok(false) => {
+                let body = match self.finish_body(Mode { mutable: false, keyed: false }) {
+                    Ok(body) => body,
+                    Err(e) => return e.to_compile_error(),
+                };
+                quote::quote! {
+                    #[automatically_derived]
+                    #[allow(unused, clippy::all, clippy::pedantic, clippy::nursery)]
+                    impl #impl_generics vale::ValidateRef for #name #ty_generics #where_clause {
+                        #ruleset
+                        #[inline]
+                        fn validate_ref(&self) -> Result<(), Vec<String>> {
+                            #body
+                        }
+                    }
+                }
+            }
+            Err(e) => return e.to_compile_error(),
+        };
 
+        // the ruleset is expanded here, so that the end of the span is logged on every return
+        let (enter, exit) = self.instrument();
+        let validate = quote::quote! {
+            #[inline]
+            fn validate(&mut self) -> Result<(), Vec<String>> {
+                #enter
+                #body
+            }
+        };
+        let validate = match syn::parse2::<crate::ruleset::Ruleset>(validate) {
+            Ok(validate) => {
+                validate.finish(crate::ruleset::Options::derived(self.max_errors.clone(), exit))
+            }
+            Err(e) => return e.to_compile_error(),
+        };
+        let validated_fields = self.validated_fields();
+        // all generated paths start with `vale`, so a renamed crate is imported under that name
+        let krate = self.krate.as_ref().map(|path| quote::quote! { use #path as vale; });
+
+        // the generated code should not trip the lints that the user enables for their own code,
+        // and should not rely on the prelude of `std`, so that it also compiles in `no_std` crates
         quote::quote! {
-            impl vale::Validate for #name {
-                #[vale::ruleset]
-                fn validate(&mut self) -> Result<(), Vec<String>> {
-                    #(#conditions;)*
+            const _: () = {
+                #krate
+                use vale::__private::prelude::*;
+
+                #[automatically_derived]
+                #[allow(unused, clippy::all, clippy::pedantic, clippy::nursery)]
+                impl #impl_generics vale::Validate for #name #ty_generics #where_clause {
+                    #validate
+
+                    #[inline]
+                    fn validate_fields(&mut self) -> Result<(), vale::FieldErrors> {
+                        let mut __vale_field_errors = vale::FieldErrors::new();
+                        #keyed_body
+                        if __vale_field_errors.is_empty() {
+                            Ok(())
+                        } else {
+                            Err(__vale_field_errors)
+                        }
+                    }
+                }
+
+                #validate_ref
+
+                #[automatically_derived]
+                #[allow(unused, clippy::all, clippy::pedantic, clippy::nursery)]
+                impl #impl_generics #name #ty_generics #where_clause {
+                    /// The names of the fields that carry validations, in the order in which they
+                    /// are declared.
+                    pub const VALIDATED_FIELDS: &'static [&'static str] = &[#(#validated_fields),*];
+                }
+            };
+        }
+    }
+
+    /// The names of the fields that carry validations. For enums, a field that occurs in several
+    /// variants is listed once.
+    fn validated_fields(&self) -> Vec<String> {
+        let fields: Vec<&FieldValidation> = match &self.data {
+            Data::Struct(validations) => validations.iter().collect(),
+            Data::Enum(variants) => variants.iter().flat_map(|v| &v.validations).collect(),
+        };
+        let mut names = Vec::new();
+        for field in fields.into_iter().filter(|f| !f.conditions.is_empty()) {
+            let name = field.key();
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        names
+    }
+
+    /// Creates the statements that enter a `tracing` span for the call to `validate`, and that log
+    /// the number of errors at the end of it.
+    #[cfg(feature = "tracing")]
+    fn instrument(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        let name = self.name.to_string();
+        let enter = quote::quote! {
+            let __vale_span = vale::__private::tracing::debug_span!("validate", entity = #name).entered();
+        };
+        let exit = quote::quote! {
+            vale::__private::tracing::debug!(errors = errors.len(), "validated {}", #name);
+        };
+        (enter, exit)
+    }
+
+    #[cfg(not(feature = "tracing"))]
+    fn instrument(&self) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+        (proc_macro2::TokenStream::new(), proc_macro2::TokenStream::new())
+    }
+
+    /// Creates the body of a validation function.
+    fn finish_body(&self, mode: Mode) -> parse::Result<proc_macro2::TokenStream> {
+        let fields = match &self.data {
+            Data::Struct(validations) => Self::finish_fields(validations, mode, |member| {
+                quote::quote! { self.#member }
+            }),
+            Data::Enum(variants) => variants
+                .iter()
+                .map(|variant| variant.finish(mode))
+                .collect::<parse::Result<Vec<_>>>()
+                .map(|arms| quote::quote! {
+                    match self {
+                        #(#arms)*
+                    }
+                }),
+        }?;
+        let container = self.finish_container()?;
+        let container = if mode.keyed && !container.is_empty() {
+            Mode::keyed("", quote::quote! { #(#container;)* })
+        } else {
+            quote::quote! { #(#container;)* }
+        };
+        Ok(quote::quote! {
+            #fields
+            #container
+        })
+    }
+
+    /// Creates the validation code for the validations placed on the struct or enum itself. These
+    /// run after the fields are validated, so they see the transformed values.
+    fn finish_container(&self) -> parse::Result<Vec<proc_macro2::TokenStream>> {
+        let label = Label { name: self.name.to_string(), indices: Vec::new(), show_values: false };
+        let mut result = Vec::new();
+        for condition in &self.conditions {
+            let message = condition.message.as_ref();
+            let rule = match condition.kind()? {
+                ValidationKind::With(stream) => {
+                    let message = match message {
+                        Some(message) => message.finish(&quote::quote! { (*self) }),
+                        None => {
+                            let message = format!("Failed to validate `{}`, value did not pass test", self.name);
+                            syn::LitStr::new(&message, proc_macro2::Span::call_site()).to_token_stream()
+                        }
+                    };
+                    quote::quote! { vale::rule!((#stream)(&mut *self), #message) }
+                }
+                ValidationKind::Predicate(expr, source) => {
+                    let message = match message {
+                        Some(message) => message.finish(&quote::quote! { (*self) }),
+                        None => {
+                            let message = format!("Failed to validate `{}`, `{}` does not hold", self.name, source);
+                            syn::LitStr::new(&message, proc_macro2::Span::call_site()).to_token_stream()
+                        }
+                    };
+                    quote::quote! { vale::rule!(#expr, #message) }
+                }
+                kind @ ValidationKind::TryWith(_) => {
+                    kind.finish(&label, &quote::quote! { (*self) }, message, true)
+                }
+                _ => {
+                    let span = condition.name.span();
+                    let msg = format!("`{}` can only be used on fields", condition.name);
+                    return Err(parse::Error::new(span, msg));
+                }
+            };
+            result.push(condition.guard(rule));
+        }
+        Ok(result)
+    }
+
+    /// Whether any of the validations needs mutable access to the entity, in which case
+    /// `vale::ValidateRef` can't be implemented.
+    fn is_mutating(&self) -> parse::Result<bool> {
+        if !self.conditions.is_empty() {
+            return Ok(true);
+        }
+        let fields: Vec<&FieldValidation> = match &self.data {
+            Data::Struct(validations) => validations.iter().collect(),
+            Data::Enum(variants) => variants.iter().flat_map(|v| &v.validations).collect(),
+        };
+        for field in fields {
+            for condition in &field.conditions {
+                if condition.kind()?.is_mutating() {
+                    return Ok(true);
                 }
             }
         }
+        Ok(false)
+    }
+
+    /// Creates the validation code for a list of fields, where `access` creates the expression
+    /// through which the field is reached.
+    fn finish_fields(
+        validations: &[FieldValidation],
+        mode: Mode,
+        access: impl Fn(&syn::Member) -> proc_macro2::TokenStream,
+    ) -> parse::Result<proc_macro2::TokenStream> {
+        let fields = validations
+            .iter()
+            .filter(|v| !(mode.keyed && v.conditions.is_empty()))
+            .map(|v| {
+                let field = v.finish(&access(&v.member), mode)?;
+                if mode.keyed {
+                    Ok(Mode::keyed(&v.key(), field))
+                } else {
+                    Ok(field)
+                }
+            })
+            .collect::<parse::Result<Vec<_>>>()?;
+        Ok(quote::quote! {
+            #(#fields;)*
+        })
     }
 }
 
-struct FieldValidation {
+struct VariantValidation {
     name: syn::Ident,
+    validations: Vec<FieldValidation>,
+}
+
+impl VariantValidation {
+    /// Creates a match arm that binds the validated fields of this variant and validates them.
+    fn finish(&self, mode: Mode) -> parse::Result<proc_macro2::TokenStream> {
+        let name = &self.name;
+        let validated: Vec<&FieldValidation> = self
+            .validations
+            .iter()
+            .filter(|v| !v.conditions.is_empty())
+            .collect();
+        let members = validated.iter().map(|v| &v.member);
+        let bindings = validated.iter().map(|v| Self::binding(&v.member));
+        let body = Validate::finish_fields(&self.validations, mode, |member| {
+            let binding = Self::binding(member);
+            quote::quote! { (*#binding) }
+        })?;
+        Ok(quote::quote! {
+            Self::#name { #(#members: #bindings,)* .. } => {
+                #body
+            }
+        })
+    }
+
+    fn binding(member: &syn::Member) -> syn::Ident {
+        match member {
+            syn::Member::Named(ident) => quote::format_ident!("__vale_{}", ident),
+            syn::Member::Unnamed(index) => quote::format_ident!("__vale_{}", index.index),
+        }
+    }
+}
+
+struct FieldValidation {
+    member: syn::Member,
+    ty: syn::Type,
+    optional: bool,
+    /// The options that were placed on the struct or enum.
+    options: Options,
+    /// The name of the field in error messages, if it was renamed.
+    rename: Option<String>,
     conditions: Vec<Condition>
 }
 
 impl FieldValidation {
-    fn parse(field: syn::Field) -> parse::Result<Self> {
+    fn parse(idx: usize, field: syn::Field) -> parse::Result<Self> {
         let mut conditions: Vec<Condition> = Vec::new();
-        for attr in field.attrs.into_iter() {
-            conditions.extend(Condition::parse(attr)?);
+        let mut rename = None;
+        if !field.attrs.iter().any(Condition::is_skip) {
+            // other attributes, such as `#[serde(...)]` or doc comments, are left alone
+            for attr in field.attrs.into_iter().filter(|attr| attr.path.is_ident("validate")) {
+                let attr = Condition::parse(attr)?;
+                if let Some(lit) = attr.rename {
+                    if rename.is_some() {
+                        let span = lit.span();
+                        return Err(parse::Error::new(span, "`rename` can only be used once per field"));
+                    }
+                    rename = Some(lit.value());
+                }
+                if let Some(path) = attr.krate {
+                    let msg = "`crate` can only be used on the struct or enum";
+                    return Err(parse::Error::new_spanned(path, msg));
+                }
+                if let Some(lit) = attr.max_errors {
+                    let msg = "`max_errors` can only be used on the struct or enum";
+                    return Err(parse::Error::new(lit.span(), msg));
+                }
+                conditions.extend(attr.conditions);
+            }
         }
+        let member = match field.ident {
+            Some(ident) => syn::Member::Named(ident),
+            None => syn::Member::Unnamed(idx.into()),
+        };
         Ok(Self {
-            name: field.ident.unwrap(),
+            member,
+            optional: Self::is_option(&field.ty),
+            ty: field.ty,
+            options: Options::default(),
+            rename,
             conditions,
         })
     }
+
+    /// Checks whether the type of the field is an `Option`, by looking at the last segment of its
+    /// path. This means that both `Option<T>` and `std::option::Option<T>` are recognised.
+    #[allow(clippy::unnecessary_map_or)]
+    fn is_option(ty: &syn::Type) -> bool {
+        match ty {
+            syn::Type::Path(syn::TypePath { qself: None, path }) => path
+                .segments
+                .last()
+                .map_or(false, |segment| segment.ident == "Option"),
+            _ => false,
+        }
+    }
+
+    /// The name of the field, which is used as its key in `vale::FieldErrors`.
+    fn key(&self) -> String {
+        match &self.member {
+            syn::Member::Named(ident) => ident.to_string(),
+            syn::Member::Unnamed(index) => index.index.to_string(),
+        }
+    }
+
+    /// The name of the field as it is shown in error messages.
+    fn label(&self) -> Label {
+        let name = self.rename.clone().unwrap_or_else(|| self.key());
+        Label { name, indices: Vec::new(), show_values: self.options.show_values }
+    }
+
+    fn finish(&self, field: &proc_macro2::TokenStream, mode: Mode) -> parse::Result<proc_macro2::TokenStream> {
+        let label = self.label();
+        let mutable = mode.mutable;
+        let borrow = borrow(mutable);
+        let mut kinds = self
+            .conditions
+            .iter()
+            .map(|c| Ok((c, c.kind()?)))
+            .collect::<parse::Result<Vec<_>>>()?;
+        if self.options.transform_first {
+            kinds.sort_by_key(|(_, kind)| !kind.is_transformer());
+        }
+        let mut required = Vec::new();
+        let mut conditions = Vec::new();
+        for (condition, kind) in kinds {
+            let message = condition.message.as_ref();
+            if let ValidationKind::Required = kind {
+                if !self.optional {
+                    let span = condition.name.span();
+                    return Err(parse::Error::new(span, "`required` can only be used on `Option` fields"));
+                }
+                required.push(condition.guard(kind.finish(&label, field, message, mutable)));
+            } else if self.optional {
+                let inner = quote::quote! { (*__vale_inner) };
+                let inner = pointee(&kind, condition, &inner, type_argument(&self.ty))?;
+                let rule = self.finish_kind(kind, &label, &inner, message, mode);
+                conditions.push(condition.guard(rule));
+            } else {
+                let field = pointee(&kind, condition, field, Some(&self.ty))?;
+                conditions.push(condition.guard(self.finish_kind(kind, &label, &field, message, mode)));
+            }
+        }
+
+        if self.optional && !conditions.is_empty() {
+            Ok(quote::quote! {
+                #(#required;)*
+                if let Some(__vale_inner) = #borrow #field {
+                    #(#conditions;)*
+                }
+            })
+        } else {
+            Ok(quote::quote! {
+                #(#required;)*
+                #(#conditions;)*
+            })
+        }
+    }
+
+    /// Creates the code for a single validation of this field. In `validate_fields`, the errors of
+    /// a `nested` value are stored under their full path, such as `address.street`, rather than
+    /// under the name of this field.
+    fn finish_kind(
+        &self,
+        kind: ValidationKind,
+        label: &Label,
+        field: &proc_macro2::TokenStream,
+        message: Option<&Message>,
+        mode: Mode,
+    ) -> proc_macro2::TokenStream {
+        match kind {
+            ValidationKind::Nested if mode.keyed && message.is_none() => {
+                let key = self.key();
+                let name = label.expr();
+                quote::quote! {
+                    if let Err(nested) = vale::Validate::validate_nested(&mut #field, #key) {
+                        for (path, nested) in nested.iter() {
+                            let nested = nested.iter().map(|e| format!("{}: {}", #name, e));
+                            __vale_field_errors.extend(path, nested);
+                        }
+                    }
+                }
+            }
+            kind => kind.finish(label, field, message, mode.mutable),
+        }
+    }
 }
 
 #[derive(Debug)]
 struct Condition {
     name: syn::Ident,
     // _parens: Option<token::Paren>,
-    content: Option<proc_macro2::TokenStream>,
+    content: Vec<proc_macro2::TokenStream>,
+    message: Option<Message>,
+    /// The expression of `when = "..."`, which is placed in the same attribute as the validation.
+    /// The validation only runs when it evaluates to `true`.
+    guard: Option<syn::Expr>,
+}
+
+/// Returns `message` or `message_fn` if `expr` is one of those names, as in the left-hand side of
+/// `message = "..."`.
+fn message_name(expr: &syn::Expr) -> Option<&syn::Ident> {
+    match expr {
+        syn::Expr::Path(path) if path.path.is_ident("message") || path.path.is_ident("message_fn") => {
+            path.path.get_ident()
+        }
+        _ => None,
+    }
+}
+
+/// A replacement for the default error message of a validation.
+#[derive(Debug)]
+enum Message {
+    /// `message = "..."`, which is used as-is.
+    Literal(syn::LitStr),
+    /// `message_fn = path`, which is called with a reference to the value to create the message.
+    Function(syn::Path),
+}
+
+impl Message {
+    /// Parses the value of `message = "..."` or `message_fn = path`, where `name` is the name
+    /// before the `=`.
+    fn parse(name: &syn::Ident, value: &syn::Expr, current: &Option<Self>) -> parse::Result<Self> {
+        if current.is_some() {
+            return Err(parse::Error::new(
+                name.span(),
+                "only one of `message` and `message_fn` can be used",
+            ));
+        }
+        match value {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(lit), .. }) if name == "message" => {
+                Ok(Self::Literal(lit.clone()))
+            }
+            syn::Expr::Path(path) if name == "message_fn" && path.qself.is_none() => {
+                Ok(Self::Function(path.path.clone()))
+            }
+            value if name == "message" => {
+                Err(parse::Error::new_spanned(value, "`message` must be a string literal"))
+            }
+            value => Err(parse::Error::new_spanned(value, "`message_fn` must be the path of a function")),
+        }
+    }
+
+    /// Creates an expression that evaluates to the message for the provided field.
+    fn finish(&self, field: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            Self::Literal(lit) => lit.to_token_stream(),
+            Self::Function(path) => quote::quote! { #path(&#field) },
+        }
+    }
+}
+
+/// The contents of a single `#[validate(...)]` attribute.
+struct Attribute {
+    conditions: Vec<Condition>,
+    /// The name of the field in error messages, from `rename = "..."`.
+    rename: Option<syn::LitStr>,
+    /// The path to the `vale` crate, from `crate = path`.
+    krate: Option<syn::Path>,
+    /// The maximum number of errors, from `max_errors = n`.
+    max_errors: Option<syn::LitInt>,
 }
 
 impl Condition {
-    fn parse(tokens: syn::Attribute) -> parse::Result<Vec<Self>> {
-        let span = proc_macro2::Span::call_site();
-        let meta_list = match tokens.parse_meta()? {
-            syn::Meta::List(l) => l,
-            syn::Meta::Path(_) | syn::Meta::NameValue(_) => {
-                return Err(parse::Error::new(span, "validations not formatted correctly"));
+    /// Parses a `#[validate(...)]` attribute. Other attributes, such as `#[serde(...)]` or doc
+    /// comments, contain no conditions.
+    fn parse(tokens: syn::Attribute) -> parse::Result<Attribute> {
+        if !tokens.path.is_ident("validate") {
+            return Ok(Attribute { conditions: Vec::new(), rename: None, krate: None, max_errors: None });
+        }
+        if tokens.tokens.is_empty() {
+            return Err(parse::Error::new_spanned(tokens, "validations not formatted correctly"));
+        }
+        let attr = tokens.parse_args_with(|input: parse::ParseStream| {
+            let mut conditions = Vec::new();
+            let mut guard = None;
+            let mut rename = None;
+            let mut krate = None;
+            let mut max_errors = None;
+            while !input.is_empty() {
+                if input.peek(syn::Token![crate]) && input.peek2(syn::Token![=]) {
+                    krate = Some(Self::parse_crate(input, &krate)?);
+                } else if input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+                    let nv: syn::MetaNameValue = input.parse()?;
+                    if nv.path.is_ident("rename") {
+                        rename = Some(Self::parse_rename(nv, &rename)?);
+                    } else if nv.path.is_ident("max_errors") {
+                        max_errors = Some(Self::parse_max_errors(nv, &max_errors)?);
+                    } else if nv.path.is_ident("predicate") {
+                        conditions.push(Self::parse_predicate(nv));
+                    } else {
+                        guard = Some(Self::parse_guard(nv, &guard)?);
+                    }
+                } else {
+                    conditions.push(Self::parse_nested(input)?);
+                }
+                if !input.is_empty() {
+                    input.parse::<syn::Token![,]>()?;
+                }
             }
+            for condition in &mut conditions {
+                condition.guard = guard.clone();
+            }
+            Ok(Attribute { conditions, rename, krate, max_errors })
+        })?;
+        Ok(attr)
+    }
+
+    /// Parses the `crate = path` argument of an attribute, which replaces the path through which
+    /// the generated code refers to `vale`.
+    fn parse_crate(input: parse::ParseStream, current: &Option<syn::Path>) -> parse::Result<syn::Path> {
+        let token: syn::Token![crate] = input.parse()?;
+        input.parse::<syn::Token![=]>()?;
+        if current.is_some() {
+            return Err(parse::Error::new(token.span, "`crate` can only be used once"));
+        }
+        input.parse()
+    }
+
+    /// Parses the `rename = "..."` argument of an attribute, which replaces the name of the field
+    /// in error messages.
+    fn parse_rename(nv: syn::MetaNameValue, current: &Option<syn::LitStr>) -> parse::Result<syn::LitStr> {
+        if current.is_some() {
+            return Err(parse::Error::new_spanned(nv, "`rename` can only be used once per field"));
+        }
+        match nv.lit {
+            syn::Lit::Str(lit) => Ok(lit),
+            lit => Err(parse::Error::new(lit.span(), "`rename` must be a string literal")),
+        }
+    }
+
+    /// Parses the `max_errors = n` argument of an attribute, which caps the number of errors that
+    /// are returned.
+    fn parse_max_errors(nv: syn::MetaNameValue, current: &Option<syn::LitInt>) -> parse::Result<syn::LitInt> {
+        if current.is_some() {
+            return Err(parse::Error::new_spanned(nv, "`max_errors` can only be used once"));
+        }
+        crate::ruleset::parse_max_errors(nv.lit)
+    }
+
+    /// Parses the `predicate = "..."` argument of an attribute, which is shorthand for
+    /// `predicate("...")`.
+    fn parse_predicate(nv: syn::MetaNameValue) -> Self {
+        let name = syn::Ident::new("predicate", nv.path.span());
+        Self { name, content: vec![nv.lit.into_token_stream()], message: None, guard: None }
+    }
+
+    /// Parses the `when = "..."` argument of an attribute, which contains the expression that
+    /// guards its validations.
+    fn parse_guard(nv: syn::MetaNameValue, current: &Option<syn::Expr>) -> parse::Result<syn::Expr> {
+        if !nv.path.is_ident("when") {
+            let msg = format!("unrecognised argument: {}", nv.path.to_token_stream());
+            return Err(parse::Error::new_spanned(nv.path, msg));
+        }
+        if current.is_some() {
+            return Err(parse::Error::new_spanned(nv, "`when` can only be used once per attribute"));
+        }
+        match nv.lit {
+            syn::Lit::Str(lit) => lit.parse(),
+            lit => Err(parse::Error::new(lit.span(), "`when` must be a string literal")),
+        }
+    }
+
+    /// Wraps the code of a validation in the guard of its attribute, if it has one.
+    fn guard(&self, rule: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match &self.guard {
+            Some(guard) => quote::quote! {
+                if #guard {
+                    #rule;
+                }
+            },
+            None => rule,
+        }
+    }
+
+    /// Checks whether the attribute is `#[validate(skip)]`, in which case all other attributes of
+    /// the field are ignored and the field is not validated.
+    fn is_skip(attr: &syn::Attribute) -> bool {
+        match Self::parse(attr.clone()) {
+            Ok(attr) => attr.conditions.iter().any(|c| c.name == "skip" && c.content.is_empty()),
+            Err(_) => false,
+        }
+    }
+
+    /// Parses a single validation, such as `len_lt(20)` or `email`. The arguments of a validation
+    /// are parsed as expressions, so paths and closures can be passed to `with`, as in
+    /// `with(|x: &mut i32| *x > 0)`.
+    fn parse_nested(input: parse::ParseStream) -> parse::Result<Self> {
+        let mut path: syn::Path = input.parse()?;
+        let name = match path.segments.pop() {
+            Some(segment) => segment.into_value().ident,
+            None => return Err(parse::Error::new_spanned(path, "expected the name of a validation")),
         };
-        let path = if let Some(path) = meta_list.path.get_ident() {
-            path
-        } else {
-            return Err(parse::Error::new(span, "validations must start with #[validate]"));
-        };
-        if path != "validate" {
-            return Err(parse::Error::new(span, "validations must start with #[validate]"));
-        }
-        let mut result = vec![];
-        for nmeta in meta_list.nested {
-            match nmeta {
-                syn::NestedMeta::Meta(syn::Meta::List(mut l)) => {
-                    let name = l.path.segments.pop().unwrap().into_value().ident;
-                    let content = l.nested.pop().unwrap().into_value().into_token_stream();
-                    result.push(Self {
-                        name,
-                        content: Some(content),
-                    })
-                },
-                syn::NestedMeta::Meta(syn::Meta::Path(mut p)) => {
-                    let name = p.segments.pop().unwrap().into_value().ident;
-                    result.push(Self {
-                        name,
-                        content: None,
-                    })
-                },
-                _ => return Err(parse::Error::new(span, "malformed validation")),
+        let mut content = Vec::new();
+        let mut message = None;
+        if input.peek(token::Paren) {
+            let args;
+            syn::parenthesized!(args in input);
+            let args = punct::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(&args)?;
+            for arg in args {
+                if let syn::Expr::Assign(assign) = &arg {
+                    if let Some(name) = message_name(&assign.left) {
+                        message = Some(Message::parse(name, &assign.right, &message)?);
+                        continue;
+                    }
+                }
+                content.push(arg.into_token_stream());
+            }
+        }
+        Ok(Self {
+            name,
+            content,
+            message,
+            guard: None,
+        })
+    }
+
+    fn kind(&self) -> parse::Result<ValidationKind> {
+        ValidationKind::parse(&self.name, &self.content)
+    }
+}
+
+/// The options that can be placed on the struct or enum, as in `#[validate(show_values)]`. These
+/// apply to all of its fields.
+#[derive(Clone, Copy, Default)]
+struct Options {
+    /// Whether the value of a field is included in its default error messages.
+    show_values: bool,
+    /// Whether the transformers of a field run before its other validations, regardless of the
+    /// order in which they are listed.
+    transform_first: bool,
+}
+
+impl Options {
+    /// Removes the option called `name` from the validations of the container, and returns
+    /// whether it was present.
+    fn take_flag(conditions: &mut Vec<Condition>, name: &str) -> bool {
+        let is_flag = |c: &Condition| c.name == name && c.content.is_empty();
+        let present = conditions.iter().any(is_flag);
+        conditions.retain(|c| !is_flag(c));
+        present
+    }
+}
+
+/// The kind of validation function that is generated.
+#[derive(Clone, Copy)]
+struct Mode {
+    /// Whether `self` is accessed by mutable reference, as in `vale::Validate`, or by shared
+    /// reference, as in `vale::ValidateRef`.
+    mutable: bool,
+    /// Whether the errors are grouped by field, as in `vale::Validate::validate_fields`.
+    keyed: bool,
+}
+
+impl Mode {
+    /// Wraps the validation code of a single field, so that its errors are stored under `key`.
+    fn keyed(key: &str, validation: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        quote::quote! {{
+            let mut errors: Vec<String> = Vec::new();
+            #validation;
+            __vale_field_errors.extend(#key, errors);
+        }}
+    }
+}
+
+/// The way in which the length validations measure a value, chosen with `count = "..."`.
+#[derive(Clone, Copy)]
+enum Length {
+    /// `count = "len"`, the default, which calls `len()`. For strings, this is the number of bytes.
+    Len,
+    /// `count = "bytes"`, the number of bytes of anything that implements `AsRef<[u8]>`, such as
+    /// strings and `Vec<u8>`.
+    Bytes,
+    /// `count = "chars"`, the number of characters of a string.
+    Chars,
+    /// `count = "graphemes"`, the number of grapheme clusters of a string, which is what a user
+    /// sees as a single character. Requires the `graphemes` feature.
+    #[cfg(feature = "graphemes")]
+    Graphemes,
+}
+
+impl Length {
+    /// Takes the `count = "..."` argument out of the arguments of a length validation, and returns
+    /// the remaining arguments.
+    fn parse(
+        content: &[proc_macro2::TokenStream],
+    ) -> parse::Result<(Self, Vec<proc_macro2::TokenStream>)> {
+        let mut length = Self::Len;
+        let mut rest = Vec::new();
+        for arg in content {
+            let nv = match syn::parse2::<syn::MetaNameValue>(arg.clone()) {
+                Ok(nv) if nv.path.is_ident("count") => nv,
+                _ => {
+                    rest.push(arg.clone());
+                    continue;
+                }
+            };
+            length = match nv.lit {
+                syn::Lit::Str(lit) if lit.value() == "len" => Self::Len,
+                syn::Lit::Str(lit) if lit.value() == "bytes" => Self::Bytes,
+                syn::Lit::Str(lit) if lit.value() == "chars" => Self::Chars,
+                syn::Lit::Str(lit) if lit.value() == "graphemes" => Self::graphemes(lit.span())?,
+                lit => {
+                    let msg = "`count` must be one of \"len\", \"bytes\", \"chars\" or \"graphemes\"";
+                    return Err(parse::Error::new(lit.span(), msg));
+                }
             };
         }
-        Ok(result)
+        Ok((length, rest))
+    }
+
+    #[cfg(feature = "graphemes")]
+    fn graphemes(_: proc_macro2::Span) -> parse::Result<Self> {
+        Ok(Self::Graphemes)
+    }
+
+    #[cfg(not(feature = "graphemes"))]
+    fn graphemes(span: proc_macro2::Span) -> parse::Result<Self> {
+        Err(parse::Error::new(span, "counting graphemes requires the `graphemes` feature"))
+    }
+
+    /// Creates an expression that evaluates to the length of the field.
+    fn finish(self, field: &proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        match self {
+            Self::Len => quote::quote! { #field.len() },
+            Self::Bytes => quote::quote! { AsRef::<[u8]>::as_ref(&#field).len() },
+            Self::Chars => quote::quote! { #field.chars().count() },
+            #[cfg(feature = "graphemes")]
+            Self::Graphemes => quote::quote! { vale::__private::grapheme_count(&#field) },
+        }
+    }
+}
+
+/// Returns the type argument of a type that has exactly one, such as the `T` in `Option<T>` or
+/// `Vec<T>`.
+fn type_argument(ty: &syn::Type) -> Option<&syn::Type> {
+    let segment = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last()?,
+        _ => return None,
+    };
+    match &segment.arguments {
+        syn::PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            syn::GenericArgument::Type(ty) => Some(ty),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the name and the type argument of a smart pointer type, such as `Box<T>`, `Rc<T>` or
+/// `Arc<T>`.
+fn pointer(ty: &syn::Type) -> Option<(String, &syn::Type)> {
+    let name = match ty {
+        syn::Type::Path(syn::TypePath { qself: None, path }) => path.segments.last()?.ident.to_string(),
+        _ => return None,
+    };
+    match name.as_str() {
+        "Box" | "Rc" | "Arc" => Some((name, type_argument(ty)?)),
+        _ => None,
+    }
+}
+
+/// Smart pointers are validated through the value they point to, so that for example `gt(0)` can
+/// be used on a `Box<i32>`. Transformers replace the pointer itself, through `vale::Transform`.
+/// Since the value behind an `Rc` or `Arc` can't be borrowed mutably, the other validations that
+/// need mutable access are not allowed on them.
+fn pointee(
+    kind: &ValidationKind,
+    condition: &Condition,
+    field: &proc_macro2::TokenStream,
+    ty: Option<&syn::Type>,
+) -> parse::Result<proc_macro2::TokenStream> {
+    let name = match ty.and_then(pointer) {
+        Some((name, _)) if !kind.is_transformer() => name,
+        _ => return Ok(field.clone()),
+    };
+    if name != "Box" && kind.is_mutating() {
+        let span = condition.name.span();
+        let msg = format!("validations that modify the value can't be used on `{}` fields", name);
+        return Err(parse::Error::new(span, msg));
+    }
+    Ok(quote::quote! { (*#field) })
+}
+
+/// The kind of reference through which a field is accessed, `&mut` when validating through
+/// `vale::Validate` and `&` when validating through `vale::ValidateRef`.
+fn borrow(mutable: bool) -> proc_macro2::TokenStream {
+    if mutable {
+        quote::quote! { &mut }
+    } else {
+        quote::quote! { & }
+    }
+}
+
+/// Lists the arguments of a validation in its description, as in `"http://", "https://"`.
+fn join(args: &[proc_macro2::TokenStream]) -> String {
+    args.iter().map(render).collect::<Vec<_>>().join(", ")
+}
+
+/// Renders the number of items in the description of `min_items` and `max_items`, as in `1 item` or
+/// `3 items`.
+fn items(count: &proc_macro2::TokenStream) -> String {
+    match render(count) {
+        count if count == "1" => "1 item".to_string(),
+        count => format!("{} items", count),
     }
+}
 
-    fn finish(&self, field_name: &syn::Ident) -> parse::Result<proc_macro2::TokenStream> {
-        let kind = ValidationKind::parse(&self.name, self.content.as_ref())?;
+/// Renders an argument of a validation for its description the way it is usually written, such as
+/// `limits::MAX` or `max_len()`, rather than the `limits :: MAX` of `TokenStream::to_string`.
+fn render(tokens: &proc_macro2::TokenStream) -> String {
+    let mut out = String::new();
+    // whether the previous token ends an operand, such as an identifier, a literal or a group
+    let mut after_operand = false;
+    // whether the previous token is a punctuation character that is joined to the next one, as the
+    // `<` in `<=`
+    let mut joint = false;
+    for token in tokens.clone() {
+        match token {
+            proc_macro2::TokenTree::Ident(_) | proc_macro2::TokenTree::Literal(_) => {
+                if after_operand {
+                    out.push(' ');
+                }
+                out.push_str(&token.to_string());
+                after_operand = true;
+                joint = false;
+            }
+            proc_macro2::TokenTree::Group(group) => {
+                let (open, close) = match group.delimiter() {
+                    proc_macro2::Delimiter::Parenthesis => ("(", ")"),
+                    proc_macro2::Delimiter::Bracket => ("[", "]"),
+                    proc_macro2::Delimiter::Brace => ("{ ", " }"),
+                    proc_macro2::Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                out.push_str(&render(&group.stream()));
+                out.push_str(close);
+                after_operand = true;
+                joint = false;
+            }
+            proc_macro2::TokenTree::Punct(punct) => {
+                let c = punct.as_char();
+                match c {
+                    ',' => out.push_str(", "),
+                    // paths, fields and unary operators are written without spaces
+                    '.' | ':' => out.push(c),
+                    _ if !after_operand && !joint => out.push(c),
+                    _ => {
+                        if !joint {
+                            out.push(' ');
+                        }
+                        out.push(c);
+                        if punct.spacing() == proc_macro2::Spacing::Alone {
+                            out.push(' ');
+                        }
+                    }
+                }
+                after_operand = false;
+                joint = punct.spacing() == proc_macro2::Spacing::Joint && c != ',';
+            }
+        }
+    }
+    out
+}
+
+/// The name of a validated value as it is shown in error messages. Elements that are validated
+/// through `each` are labelled with their index, and those validated through `each_key` or
+/// `each_value` with their key, which are only known at runtime.
+#[derive(Clone)]
+struct Label {
+    name: String,
+    indices: Vec<Index>,
+    /// Whether the value is shown after its name, which requires it to implement `Debug`.
+    show_values: bool,
+}
+
+/// The variable that holds the position of an element in its collection, which is part of its
+/// `Label`.
+#[derive(Clone)]
+struct Index {
+    ident: syn::Ident,
+    /// Whether this is the key of a map, which is shown through its `Debug` implementation, as in
+    /// `settings["timeout"]`.
+    key: bool,
+}
 
-        Ok(kind.finish(field_name))
+impl Label {
+    /// Creates an expression that evaluates to the label, for example `tags[2]`.
+    fn expr(&self) -> proc_macro2::TokenStream {
+        let name = &self.name;
+        if self.indices.is_empty() {
+            return quote::quote! { #name };
+        }
+        let mut format = name.clone();
+        for index in &self.indices {
+            format.push_str(if index.key { "[{:?}]" } else { "[{}]" });
+        }
+        let indices = self.indices.iter().map(|index| &index.ident);
+        quote::quote! { format!(#format, #(#indices),*) }
     }
 }
 
+/// The validations that can be applied to a field. Each validation consumes a fixed number of
+/// arguments from the attribute, which is listed per variant.
 enum ValidationKind {
+    /// `lt(max)`, one argument.
     Lt(proc_macro2::TokenStream),
+    /// `eq(value)`, one argument.
     Eq(proc_macro2::TokenStream),
+    /// `gt(min)`, one argument.
     Gt(proc_macro2::TokenStream),
+    /// `neq(value)` or `ne(value)`, one argument.
     Neq(proc_macro2::TokenStream),
-    LenLt(proc_macro2::TokenStream),
-    LenEq(proc_macro2::TokenStream),
-    LenGt(proc_macro2::TokenStream),
-    LenNeq(proc_macro2::TokenStream),
+    /// `lte(max)`, one argument.
+    Lte(proc_macro2::TokenStream),
+    /// `gte(min)`, one argument.
+    Gte(proc_macro2::TokenStream),
+    /// `positive`, no arguments.
+    Positive,
+    /// `negative`, no arguments.
+    Negative,
+    /// `non_zero`, no arguments.
+    NonZero,
+    /// `divisible_by(divisor)`, one argument.
+    DivisibleBy(proc_macro2::TokenStream),
+    /// `len_lt(max)`, one argument. Like the other length validations, this also accepts
+    /// `count = "..."`, which is parsed into a `Length`.
+    LenLt(proc_macro2::TokenStream, Length),
+    /// `len_eq(len)`, one argument.
+    LenEq(proc_macro2::TokenStream, Length),
+    /// `len_gt(min)`, one argument.
+    LenGt(proc_macro2::TokenStream, Length),
+    /// `len_neq(len)`, one argument.
+    LenNeq(proc_macro2::TokenStream, Length),
+    /// `len_range(min, max)`, two arguments.
+    LenRange(proc_macro2::TokenStream, proc_macro2::TokenStream, Length),
+    /// `not_empty`, no arguments.
+    NotEmpty,
+    /// `min_items(min)`, one argument.
+    MinItems(proc_macro2::TokenStream),
+    /// `max_items(max)`, one argument.
+    MaxItems(proc_macro2::TokenStream),
+    /// `unique_items`, no arguments.
+    UniqueItems,
+    /// `matches_field(field)`, one argument, which is the name of another field of the struct.
+    MatchesField(syn::Ident),
+    /// `len_eq_field(field)`, one argument, which is the name of another field of the struct.
+    LenEqField(syn::Ident),
+    /// `parse_into(field)`, one argument, which is the name of another field of the struct.
+    ParseInto(syn::Ident),
+    /// `range(min, max)`, two arguments.
+    Range(proc_macro2::TokenStream, proc_macro2::TokenStream),
+    /// `range_exclusive(min, max)`, two arguments.
+    RangeExclusive(proc_macro2::TokenStream, proc_macro2::TokenStream),
+    /// `one_of(values...)`, one or more arguments.
+    OneOf(Vec<proc_macro2::TokenStream>),
+    /// `not_one_of(values...)`, one or more arguments.
+    NotOneOf(Vec<proc_macro2::TokenStream>),
+    /// `contains(pattern)`, one argument.
+    Contains(proc_macro2::TokenStream),
+    /// `starts_with(pattern)`, one argument.
+    StartsWith(proc_macro2::TokenStream),
+    /// `ends_with(pattern)`, one argument.
+    EndsWith(proc_macro2::TokenStream),
+    /// `starts_with_any(patterns...)`, at least one argument.
+    StartsWithAny(Vec<proc_macro2::TokenStream>),
+    /// `ends_with_any(patterns...)`, at least one argument.
+    EndsWithAny(Vec<proc_macro2::TokenStream>),
+    /// `eq_ignore_case(value)`, one argument.
+    EqIgnoreCase(proc_macro2::TokenStream),
+    /// `with(function)`, one argument.
     With(proc_macro2::TokenStream),
+    /// `with_args(function, args...)`, the function followed by any number of arguments.
+    WithArgs(proc_macro2::TokenStream, Vec<proc_macro2::TokenStream>),
+    /// `try_with(function)`, one argument.
+    TryWith(proc_macro2::TokenStream),
+    /// `predicate("expr")` or `predicate = "expr"`, one argument, which is a string literal that
+    /// contains a boolean expression. Also contains the source of the expression, for the error
+    /// message.
+    Predicate(proc_macro2::TokenStream, String),
+    /// `regex(pattern)`, one argument.
+    Regex(proc_macro2::TokenStream),
+    /// `email`, no arguments.
+    Email,
+    /// `url` or `url(scheme = "...")`, at most one argument.
+    Url(Option<syn::LitStr>),
+    /// `phone` or `phone(format = "e164")`, at most one argument. Contains whether the number must
+    /// be in the E.164 format.
+    Phone(bool),
+    /// `hex`, no arguments.
+    Hex,
+    /// `base64`, no arguments. Requires the `base64` feature.
+    #[cfg(feature = "base64")]
+    Base64,
+    /// `uuid` or `uuid(version = n)`, at most one argument. Contains the required version, if any.
+    /// Requires the `uuid` feature.
+    #[cfg(feature = "uuid")]
+    Uuid(Option<syn::LitInt>),
+    /// `credit_card`, no arguments.
+    CreditCard,
+    /// `future`, no arguments. Requires the `chrono` feature.
+    Future,
+    /// `past`, no arguments. Requires the `chrono` feature.
+    Past,
+    /// `ipv4`, no arguments.
+    Ipv4,
+    /// `ipv6`, no arguments.
+    Ipv6,
+    /// `ip`, no arguments.
+    Ip,
+    /// `alphanumeric`, no arguments.
+    Alphanumeric,
+    /// `ascii_alphanumeric`, no arguments.
+    AsciiAlphanumeric,
+    /// `slug`, no arguments.
+    Slug,
+    /// `ascii`, no arguments.
+    Ascii,
+    /// `numeric`, no arguments.
+    Numeric,
+    /// `trimmed`, no arguments.
+    Trimmed,
+    /// `required`, no arguments.
+    Required,
+    /// `nested`, no arguments.
+    Nested,
+    /// `each(validations...)`, one or more arguments, each of which is a validation.
+    Each(Vec<(ValidationKind, Option<Message>)>),
+    /// `each_key(validations...)`, one or more arguments, each of which is a validation that does
+    /// not modify the value.
+    EachKey(Vec<(ValidationKind, Option<Message>)>),
+    /// `each_value(validations...)`, one or more arguments, each of which is a validation.
+    EachValue(Vec<(ValidationKind, Option<Message>)>),
+    /// `trim`, no arguments.
     Trim,
+    /// `to_lower_case`, no arguments.
     ToLowerCase,
+    /// `to_upper_case`, no arguments.
+    ToUpperCase,
+    /// `capitalize`, no arguments.
+    Capitalize,
+    /// `truncate(max_chars)`, one argument.
+    Truncate(proc_macro2::TokenStream),
+    /// `default_if_empty(value)`, one argument.
+    DefaultIfEmpty(proc_macro2::TokenStream),
 }
 
 impl ValidationKind {
-    fn parse(name: &syn::Ident, content: Option<&proc_macro2::TokenStream>) -> parse::Result<Self> {
-        let span = proc_macro2::Span::call_site();
+    fn parse(name: &syn::Ident, content: &[proc_macro2::TokenStream]) -> parse::Result<Self> {
+        let span = name.span();
         let res = match name.to_string().as_str() {
-            "lt" => Self::Lt(content.unwrap().clone()),
-            "eq" => Self::Eq(content.unwrap().clone()),
-            "gt" => Self::Gt(content.unwrap().clone()),
-            "neq" => Self::Neq(content.unwrap().clone()),
-            "len_lt" => Self::LenLt(content.unwrap().clone()),
-            "len_eq" => Self::LenEq(content.unwrap().clone()),
-            "len_gt" => Self::LenGt(content.unwrap().clone()),
-            "len_neq" => Self::LenNeq(content.unwrap().clone()),
-            "with" => Self::With(content.unwrap().clone()),
-            "trim" => Self::Trim,
-            "to_lower_case" => Self::ToLowerCase,
-            otherwise => return Err(parse::Error::new(span, &format!("unrecognised attribute: {}", otherwise)))
+            "lt" => Self::Lt(Self::args(name, content, 1)?[0].clone()),
+            "eq" => Self::Eq(Self::args(name, content, 1)?[0].clone()),
+            "gt" => Self::Gt(Self::args(name, content, 1)?[0].clone()),
+            "neq" | "ne" => Self::Neq(Self::args(name, content, 1)?[0].clone()),
+            "lte" => Self::Lte(Self::args(name, content, 1)?[0].clone()),
+            "gte" => Self::Gte(Self::args(name, content, 1)?[0].clone()),
+            "positive" => Self::args(name, content, 0).map(|_| Self::Positive)?,
+            "negative" => Self::args(name, content, 0).map(|_| Self::Negative)?,
+            "non_zero" => Self::args(name, content, 0).map(|_| Self::NonZero)?,
+            "divisible_by" => Self::DivisibleBy(Self::args(name, content, 1)?[0].clone()),
+            "len_lt" | "len_eq" | "len_gt" | "len_neq" => {
+                let (length, content) = Length::parse(content)?;
+                let arg = Self::args(name, &content, 1)?[0].clone();
+                match name.to_string().as_str() {
+                    "len_lt" => Self::LenLt(arg, length),
+                    "len_eq" => Self::LenEq(arg, length),
+                    "len_gt" => Self::LenGt(arg, length),
+                    _ => Self::LenNeq(arg, length),
+                }
+            }
+            "len_range" => {
+                let (length, content) = Length::parse(content)?;
+                let args = Self::args(name, &content, 2)?;
+                Self::LenRange(args[0].clone(), args[1].clone(), length)
+            }
+            "bytes_len_lt" | "bytes_len_eq" | "bytes_len_gt" | "bytes_len_neq" => {
+                let arg = Self::args(name, content, 1)?[0].clone();
+                match name.to_string().as_str() {
+                    "bytes_len_lt" => Self::LenLt(arg, Length::Bytes),
+                    "bytes_len_eq" => Self::LenEq(arg, Length::Bytes),
+                    "bytes_len_gt" => Self::LenGt(arg, Length::Bytes),
+                    _ => Self::LenNeq(arg, Length::Bytes),
+                }
+            }
+            "bytes_len_range" => {
+                let args = Self::args(name, content, 2)?;
+                Self::LenRange(args[0].clone(), args[1].clone(), Length::Bytes)
+            }
+            "grapheme_len_lt" | "grapheme_len_eq" | "grapheme_len_gt" | "grapheme_len_neq" => {
+                let length = Length::graphemes(name.span())?;
+                let arg = Self::args(name, content, 1)?[0].clone();
+                match name.to_string().as_str() {
+                    "grapheme_len_lt" => Self::LenLt(arg, length),
+                    "grapheme_len_eq" => Self::LenEq(arg, length),
+                    "grapheme_len_gt" => Self::LenGt(arg, length),
+                    _ => Self::LenNeq(arg, length),
+                }
+            }
+            "grapheme_len_range" => {
+                let length = Length::graphemes(name.span())?;
+                let args = Self::args(name, content, 2)?;
+                Self::LenRange(args[0].clone(), args[1].clone(), length)
+            }
+            "not_empty" => Self::args(name, content, 0).map(|_| Self::NotEmpty)?,
+            "min_items" => Self::MinItems(Self::args(name, content, 1)?[0].clone()),
+            "max_items" => Self::MaxItems(Self::args(name, content, 1)?[0].clone()),
+            "unique_items" => Self::args(name, content, 0).map(|_| Self::UniqueItems)?,
+            "matches_field" => Self::MatchesField(syn::parse2(Self::args(name, content, 1)?[0].clone())?),
+            "len_eq_field" => Self::LenEqField(syn::parse2(Self::args(name, content, 1)?[0].clone())?),
+            "parse_into" => Self::ParseInto(syn::parse2(Self::args(name, content, 1)?[0].clone())?),
+            "range" => {
+                let args = Self::args(name, content, 2)?;
+                Self::Range(args[0].clone(), args[1].clone())
+            }
+            "range_exclusive" => {
+                let args = Self::args(name, content, 2)?;
+                Self::RangeExclusive(args[0].clone(), args[1].clone())
+            }
+            "one_of" => Self::OneOf(Self::variadic_args(name, content)?.to_vec()),
+            "not_one_of" => Self::NotOneOf(Self::variadic_args(name, content)?.to_vec()),
+            "contains" => Self::Contains(Self::args(name, content, 1)?[0].clone()),
+            "starts_with" => Self::StartsWith(Self::args(name, content, 1)?[0].clone()),
+            "ends_with" => Self::EndsWith(Self::args(name, content, 1)?[0].clone()),
+            "starts_with_any" => Self::StartsWithAny(Self::variadic_args(name, content)?.to_vec()),
+            "ends_with_any" => Self::EndsWithAny(Self::variadic_args(name, content)?.to_vec()),
+            "eq_ignore_case" => Self::EqIgnoreCase(Self::args(name, content, 1)?[0].clone()),
+            "with" => Self::With(Self::args(name, content, 1)?[0].clone()),
+            "with_args" => {
+                let args = Self::variadic_args(name, content)?;
+                Self::WithArgs(args[0].clone(), args[1..].to_vec())
+            }
+            "try_with" => Self::TryWith(Self::args(name, content, 1)?[0].clone()),
+            "predicate" => Self::parse_predicate(&Self::args(name, content, 1)?[0])?,
+            "regex" => Self::Regex(Self::parse_regex(name, content)?),
+            "email" => Self::args(name, content, 0).map(|_| Self::Email)?,
+            "url" => Self::Url(Self::parse_url(name, content)?),
+            "phone" => Self::Phone(Self::parse_phone(name, content)?),
+            "hex" => Self::args(name, content, 0).map(|_| Self::Hex)?,
+            "base64" => Self::parse_base64(name, content)?,
+            "uuid" => Self::parse_uuid(name, content)?,
+            "credit_card" => Self::args(name, content, 0).map(|_| Self::CreditCard)?,
+            "future" => Self::parse_moment(name, content, Self::Future)?,
+            "past" => Self::parse_moment(name, content, Self::Past)?,
+            "ipv4" => Self::args(name, content, 0).map(|_| Self::Ipv4)?,
+            "ipv6" => Self::args(name, content, 0).map(|_| Self::Ipv6)?,
+            "ip" => Self::args(name, content, 0).map(|_| Self::Ip)?,
+            "alphanumeric" => Self::args(name, content, 0).map(|_| Self::Alphanumeric)?,
+            "ascii_alphanumeric" => Self::args(name, content, 0).map(|_| Self::AsciiAlphanumeric)?,
+            "slug" => Self::args(name, content, 0).map(|_| Self::Slug)?,
+            "ascii" => Self::args(name, content, 0).map(|_| Self::Ascii)?,
+            "numeric" => Self::args(name, content, 0).map(|_| Self::Numeric)?,
+            "trimmed" => Self::args(name, content, 0).map(|_| Self::Trimmed)?,
+            "required" => Self::args(name, content, 0).map(|_| Self::Required)?,
+            "nested" => Self::args(name, content, 0).map(|_| Self::Nested)?,
+            "each" => Self::Each(Self::parse_each(name, content)?),
+            "each_key" => Self::EachKey(Self::parse_each(name, content)?),
+            "each_value" => Self::EachValue(Self::parse_each(name, content)?),
+            "trim" => Self::args(name, content, 0).map(|_| Self::Trim)?,
+            "to_lower_case" => Self::args(name, content, 0).map(|_| Self::ToLowerCase)?,
+            "to_upper_case" => Self::args(name, content, 0).map(|_| Self::ToUpperCase)?,
+            "capitalize" => Self::args(name, content, 0).map(|_| Self::Capitalize)?,
+            "truncate" => Self::Truncate(Self::args(name, content, 1)?[0].clone()),
+            "default_if_empty" => Self::DefaultIfEmpty(Self::args(name, content, 1)?[0].clone()),
+            otherwise => return Err(parse::Error::new(span, format!("unrecognised attribute: {}", otherwise)))
         };
 
         Ok(res)
     }
 
-    fn finish(self, name: &syn::Ident) -> proc_macro2::TokenStream {
+    /// Returns the arguments of the validation called `name`, or an error if it did not receive
+    /// exactly `count` of them.
+    fn args<'a>(
+        name: &syn::Ident,
+        content: &'a [proc_macro2::TokenStream],
+        count: usize,
+    ) -> parse::Result<&'a [proc_macro2::TokenStream]> {
+        if content.len() == count {
+            return Ok(content);
+        }
+        let span = name.span();
+        let expected = match count {
+            0 => "no arguments".to_string(),
+            1 => "one argument".to_string(),
+            n => format!("{} arguments", n),
+        };
+        let msg = format!("`{}` takes {}, got {}", name, expected, content.len());
+        Err(parse::Error::new(span, msg))
+    }
+
+    /// Returns the arguments of the validation called `name`, or an error if it did not receive
+    /// any.
+    fn variadic_args<'a>(
+        name: &syn::Ident,
+        content: &'a [proc_macro2::TokenStream],
+    ) -> parse::Result<&'a [proc_macro2::TokenStream]> {
+        if content.is_empty() {
+            let span = name.span();
+            let msg = format!("`{}` takes at least one argument", name);
+            return Err(parse::Error::new(span, msg));
+        }
+        Ok(content)
+    }
+
+    /// Whether this validation modifies the value, or otherwise needs mutable access to it.
+    fn is_mutating(&self) -> bool {
         match self {
-            Self::Lt(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name < #stream,
-                    format!("Failed to validate field `{}`, value too high", stringify!(#name)),
-                )
-            },
-            Self::Eq(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name == #stream,
-                    format!("Failed to validate field `{}`, value incorrect", stringify!(#name)),
-                )
+            Self::With(_) | Self::WithArgs(..) | Self::TryWith(_) => true,
+            Self::Nested | Self::ParseInto(_) => true,
+            Self::Each(validations) | Self::EachValue(validations) => {
+                validations.iter().any(|(kind, _)| kind.is_mutating())
+            }
+            kind => kind.is_transformer(),
+        }
+    }
+
+    /// Whether this validation refers to another field of the struct through `self`.
+    fn uses_sibling(&self) -> bool {
+        matches!(
+            self,
+            Self::MatchesField(_) | Self::LenEqField(_) | Self::ParseInto(_) | Self::Predicate(..)
+        )
+    }
+
+    /// Whether this validation is a transformer, which always succeeds and modifies the value.
+    fn is_transformer(&self) -> bool {
+        matches!(
+            self,
+            Self::Trim
+                | Self::ToLowerCase
+                | Self::ToUpperCase
+                | Self::Capitalize
+                | Self::Truncate(_)
+                | Self::DefaultIfEmpty(_)
+        )
+    }
+
+    /// Parses the validations that are passed to `each`, `each_key` or `each_value`, which are
+    /// applied to every element. Since the keys of a map can't be modified, `each_key` only accepts
+    /// validations that don't.
+    fn parse_each(
+        name: &syn::Ident,
+        content: &[proc_macro2::TokenStream],
+    ) -> parse::Result<Vec<(ValidationKind, Option<Message>)>> {
+        let mut result = Vec::new();
+        for arg in Self::variadic_args(name, content)? {
+            let condition = parse::Parser::parse2(Condition::parse_nested, arg.clone())?;
+            let span = condition.name.span();
+            let kind = condition.kind()?;
+            if let Self::Required = kind {
+                let msg = format!("`required` can not be used inside `{}`", name);
+                return Err(parse::Error::new(span, msg));
+            }
+            if kind.uses_sibling() {
+                let msg = format!("`{}` can not be used inside `{}`", condition.name, name);
+                return Err(parse::Error::new(span, msg));
+            }
+            if name == "each_key" && kind.is_mutating() {
+                let name = &condition.name;
+                let msg = format!("`{}` can not be used inside `each_key`, as keys can't be modified", name);
+                return Err(parse::Error::new(span, msg));
+            }
+            result.push((kind, condition.message));
+        }
+        Ok(result)
+    }
+
+    /// Parses the string literal of `predicate` into the expression that it contains.
+    fn parse_predicate(content: &proc_macro2::TokenStream) -> parse::Result<Self> {
+        let source: syn::LitStr = match syn::parse2(content.clone()) {
+            Ok(source) => source,
+            Err(_) => {
+                let msg = "`predicate` must be a string literal, as in `predicate = \"self.start < self.end\"`";
+                return Err(parse::Error::new_spanned(content, msg));
+            }
+        };
+        let expr: syn::Expr = source.parse()?;
+        Ok(Self::Predicate(expr.into_token_stream(), source.value()))
+    }
+
+    /// Parses the pattern of `regex`, which is compiled here so that an invalid pattern is reported
+    /// at compile time.
+    #[cfg(feature = "regex")]
+    fn parse_regex(
+        name: &syn::Ident,
+        content: &[proc_macro2::TokenStream],
+    ) -> parse::Result<proc_macro2::TokenStream> {
+        let arg = &Self::args(name, content, 1)?[0];
+        let pattern: syn::LitStr = syn::parse2(arg.clone())?;
+        if let Err(e) = regex::Regex::new(&pattern.value()) {
+            return Err(parse::Error::new(pattern.span(), format!("invalid regex: {}", e)));
+        }
+        Ok(arg.clone())
+    }
+
+    #[cfg(not(feature = "regex"))]
+    fn parse_regex(
+        name: &syn::Ident,
+        _: &[proc_macro2::TokenStream],
+    ) -> parse::Result<proc_macro2::TokenStream> {
+        let span = name.span();
+        Err(parse::Error::new(span, "the `regex` validator requires the `regex` feature"))
+    }
+
+    /// Parses the optional `scheme = "..."` argument of `url`.
+    #[cfg(feature = "url")]
+    fn parse_url(
+        name: &syn::Ident,
+        content: &[proc_macro2::TokenStream],
+    ) -> parse::Result<Option<syn::LitStr>> {
+        let span = name.span();
+        let arg = match content {
+            [] => return Ok(None),
+            [arg] => arg,
+            _ => return Err(parse::Error::new(span, "`url` takes at most one argument")),
+        };
+        match syn::parse2(arg.clone())? {
+            syn::MetaNameValue { path, lit: syn::Lit::Str(scheme), .. } if path.is_ident("scheme") => {
+                Ok(Some(scheme))
+            }
+            _ => Err(parse::Error::new_spanned(arg, "the argument of `url` must be `scheme = \"...\"`")),
+        }
+    }
+
+    #[cfg(not(feature = "url"))]
+    fn parse_url(name: &syn::Ident, _: &[proc_macro2::TokenStream]) -> parse::Result<Option<syn::LitStr>> {
+        let span = name.span();
+        Err(parse::Error::new(span, "the `url` validator requires the `url` feature"))
+    }
+
+    /// Parses the optional `format = "..."` argument of `phone`, and returns whether the E.164
+    /// format is required.
+    #[cfg(feature = "phone")]
+    fn parse_phone(name: &syn::Ident, content: &[proc_macro2::TokenStream]) -> parse::Result<bool> {
+        let span = name.span();
+        let arg = match content {
+            [] => return Ok(false),
+            [arg] => arg,
+            _ => return Err(parse::Error::new(span, "`phone` takes at most one argument")),
+        };
+        match syn::parse2(arg.clone())? {
+            syn::MetaNameValue { path, lit: syn::Lit::Str(format), .. } if path.is_ident("format") => {
+                match format.value().as_str() {
+                    "e164" => Ok(true),
+                    "loose" => Ok(false),
+                    _ => {
+                        let msg = "`format` must be either \"e164\" or \"loose\"";
+                        Err(parse::Error::new(format.span(), msg))
+                    }
+                }
+            }
+            _ => Err(parse::Error::new_spanned(arg, "the argument of `phone` must be `format = \"...\"`")),
+        }
+    }
+
+    #[cfg(not(feature = "phone"))]
+    fn parse_phone(name: &syn::Ident, _: &[proc_macro2::TokenStream]) -> parse::Result<bool> {
+        let span = name.span();
+        Err(parse::Error::new(span, "the `phone` validator requires the `phone` feature"))
+    }
+
+    #[cfg(feature = "base64")]
+    fn parse_base64(name: &syn::Ident, content: &[proc_macro2::TokenStream]) -> parse::Result<Self> {
+        Self::args(name, content, 0).map(|_| Self::Base64)
+    }
+
+    #[cfg(not(feature = "base64"))]
+    fn parse_base64(name: &syn::Ident, _: &[proc_macro2::TokenStream]) -> parse::Result<Self> {
+        let span = name.span();
+        Err(parse::Error::new(span, "the `base64` validator requires the `base64` feature"))
+    }
+
+    /// Parses `uuid`, with its optional `version = n` argument.
+    #[cfg(feature = "uuid")]
+    fn parse_uuid(name: &syn::Ident, content: &[proc_macro2::TokenStream]) -> parse::Result<Self> {
+        let span = name.span();
+        let arg = match content {
+            [] => return Ok(Self::Uuid(None)),
+            [arg] => arg,
+            _ => return Err(parse::Error::new(span, "`uuid` takes at most one argument")),
+        };
+        match syn::parse2(arg.clone())? {
+            syn::MetaNameValue { path, lit: syn::Lit::Int(version), .. } if path.is_ident("version") => {
+                match version.base10_parse::<usize>()? {
+                    1..=8 => Ok(Self::Uuid(Some(version))),
+                    _ => Err(parse::Error::new(version.span(), "`version` must be between 1 and 8")),
+                }
+            }
+            _ => Err(parse::Error::new_spanned(arg, "the argument of `uuid` must be `version = n`")),
+        }
+    }
+
+    #[cfg(not(feature = "uuid"))]
+    fn parse_uuid(name: &syn::Ident, _: &[proc_macro2::TokenStream]) -> parse::Result<Self> {
+        let span = name.span();
+        Err(parse::Error::new(span, "the `uuid` validator requires the `uuid` feature"))
+    }
+
+    /// Parses `future` or `past`, which compare the value against the current time.
+    #[cfg(feature = "chrono")]
+    fn parse_moment(
+        name: &syn::Ident,
+        content: &[proc_macro2::TokenStream],
+        kind: Self,
+    ) -> parse::Result<Self> {
+        Self::args(name, content, 0).map(|_| kind)
+    }
+
+    #[cfg(not(feature = "chrono"))]
+    fn parse_moment(name: &syn::Ident, _: &[proc_macro2::TokenStream], _: Self) -> parse::Result<Self> {
+        let msg = format!("the `{}` validator requires the `chrono` feature", name);
+        Err(parse::Error::new(name.span(), msg))
+    }
+
+    /// Creates the code for the validations of `each`, `each_key` or `each_value`, which run on the
+    /// element that `item` refers to. The element is labelled with `index` in error messages, and
+    /// `message` replaces the default messages of the validations that don't have their own.
+    fn finish_each(
+        validations: Vec<(ValidationKind, Option<Message>)>,
+        label: &Label,
+        index: Index,
+        item: &syn::Ident,
+        message: Option<&Message>,
+        mutable: bool,
+    ) -> Vec<proc_macro2::TokenStream> {
+        let mut inner = label.clone();
+        inner.indices.push(index);
+        validations
+            .into_iter()
+            .map(|(kind, inner_message)| {
+                let inner_message = inner_message.as_ref().or(message);
+                kind.finish(&inner, &quote::quote! { (*#item) }, inner_message, mutable)
+            })
+            .collect()
+    }
+
+    /// Creates the code for this validation. The `name` of the field is used in the error messages,
+    /// while `field` is the expression through which the value of the field is accessed. If a
+    /// `message` is provided, it replaces the default error message.
+    fn finish(
+        self,
+        label: &Label,
+        field: &proc_macro2::TokenStream,
+        message: Option<&Message>,
+        mutable: bool,
+    ) -> proc_macro2::TokenStream {
+        let name = label.expr();
+        let (condition, description): (_, String) = match self {
+            Self::Lt(stream) => (quote::quote! { #field < #stream }, "value too high".into()),
+            Self::Eq(stream) => (quote::quote! { #field == #stream }, "value incorrect".into()),
+            Self::Gt(stream) => (quote::quote! { #field > #stream }, "value too low".into()),
+            Self::Neq(stream) => (quote::quote! { #field != #stream }, "value not allowed".into()),
+            Self::Lte(stream) => {
+                let description = format!("value must be at most {}", render(&stream));
+                (quote::quote! { #field <= #stream }, description)
+            }
+            Self::Gte(stream) => {
+                let description = format!("value must be at least {}", render(&stream));
+                (quote::quote! { #field >= #stream }, description)
+            }
+            Self::Positive => (
+                quote::quote! { #field > vale::__private::zero_of(&#field) },
+                "value is not positive".into(),
+            ),
+            Self::Negative => (
+                quote::quote! { #field < vale::__private::zero_of(&#field) },
+                "value is not negative".into(),
+            ),
+            Self::NonZero => (
+                quote::quote! { #field != vale::__private::zero_of(&#field) },
+                "value is zero".into(),
+            ),
+            Self::DivisibleBy(stream) => {
+                let description = format!("not a multiple of {}", render(&stream));
+                let condition = quote::quote! {{
+                    #[allow(clippy::modulo_one)]
+                    let remainder = #field % #stream;
+                    remainder == 0
+                }};
+                (condition, description)
+            }
+            Self::LenLt(stream, length) => {
+                let len = length.finish(field);
+                (quote::quote! { #len < #stream }, "value too long".into())
+            }
+            Self::LenEq(stream, length) => {
+                let len = length.finish(field);
+                (quote::quote! { #len == #stream }, "value of incorrect length".into())
+            }
+            Self::LenGt(stream, length) => {
+                let len = length.finish(field);
+                (quote::quote! { #len > #stream }, "value too short".into())
+            }
+            Self::LenNeq(stream, length) => {
+                let len = length.finish(field);
+                (quote::quote! { #len != #stream }, "value of disallowed length".into())
+            }
+            Self::LenRange(min, max, length) => {
+                let len = length.finish(field);
+                (quote::quote! { #len >= #min && #len <= #max }, "length out of range".into())
+            }
+            Self::NotEmpty => (quote::quote! { !#field.is_empty() }, "value must not be empty".into()),
+            Self::MinItems(stream) => {
+                let description = format!("must contain at least {}", items(&stream));
+                (quote::quote! { #field.len() >= #stream }, description)
+            }
+            Self::MaxItems(stream) => {
+                let description = format!("must contain at most {}", items(&stream));
+                (quote::quote! { #field.len() <= #stream }, description)
+            }
+            Self::UniqueItems => (
+                quote::quote! { vale::__private::all_unique(&#field) },
+                "contains duplicate items".into(),
+            ),
+            Self::ParseInto(other) => {
+                let description = format!("value could not be parsed into `{}`", other);
+                let condition = quote::quote! {
+                    match #field.parse() {
+                        Ok(value) => {
+                            self.#other = value;
+                            true
+                        }
+                        Err(_) => false,
+                    }
+                };
+                (condition, description)
+            }
+            Self::MatchesField(other) => {
+                let description = format!("value does not match `{}`", other);
+                (quote::quote! { #field == self.#other }, description)
+            }
+            Self::LenEqField(other) => {
+                let description = format!("length does not match that of `{}`", other);
+                (quote::quote! { #field.len() == self.#other.len() }, description)
+            }
+            Self::Range(min, max) => {
+                let description = format!("value out of range [{}, {}]", render(&min), render(&max));
+                (quote::quote! { #field >= #min && #field <= #max }, description)
+            }
+            Self::RangeExclusive(min, max) => {
+                let description = format!("value out of range ({}, {})", render(&min), render(&max));
+                (quote::quote! { #field > #min && #field < #max }, description)
+            }
+            Self::OneOf(values) => (
+                quote::quote! { #(#field == #values)||* },
+                "value not in allowed set".into(),
+            ),
+            Self::NotOneOf(values) => (
+                quote::quote! { #(#field != #values)&&* },
+                "value not allowed".into(),
+            ),
+            Self::Contains(stream) => {
+                let description = format!("value does not contain {}", render(&stream));
+                (quote::quote! { #field.contains(#stream) }, description)
+            }
+            Self::StartsWith(stream) => {
+                let description = format!("value does not start with {}", render(&stream));
+                (quote::quote! { #field.starts_with(#stream) }, description)
+            }
+            Self::EndsWith(stream) => {
+                let description = format!("value does not end with {}", render(&stream));
+                (quote::quote! { #field.ends_with(#stream) }, description)
+            }
+            Self::StartsWithAny(patterns) => {
+                let description = format!("value does not start with any of {}", join(&patterns));
+                let condition = quote::quote! {
+                    [#(#patterns),*].iter().any(|__vale_pattern| #field.starts_with(*__vale_pattern))
+                };
+                (condition, description)
+            }
+            Self::EndsWithAny(patterns) => {
+                let description = format!("value does not end with any of {}", join(&patterns));
+                let condition = quote::quote! {
+                    [#(#patterns),*].iter().any(|__vale_pattern| #field.ends_with(*__vale_pattern))
+                };
+                (condition, description)
+            }
+            Self::EqIgnoreCase(stream) => {
+                let description = format!("value is not equal to {}, ignoring case", render(&stream));
+                (quote::quote! { #field.eq_ignore_ascii_case(#stream) }, description)
+            }
+            Self::With(stream) => (
+                quote::quote! { (#stream)(&mut #field) },
+                "value did not pass test".into(),
+            ),
+            Self::WithArgs(function, args) => (
+                quote::quote! { (#function)(&mut #field, #(#args),*) },
+                "value did not pass test".into(),
+            ),
+            Self::Predicate(expr, source) => (expr.clone(), format!("`{}` does not hold", source)),
+            Self::Regex(stream) => (
+                quote::quote! {{
+                    static PATTERN: vale::__private::Lazy<vale::__private::Regex> =
+                        vale::__private::Lazy::new(|| vale::__private::Regex::new(#stream).unwrap());
+                    PATTERN.is_match(&#field)
+                }},
+                "value does not match pattern".into(),
+            ),
+            Self::Email => (
+                quote::quote! { vale::is_email(&#field) },
+                "not a valid email address".into(),
+            ),
+            Self::Url(None) => (quote::quote! { vale::is_url(&#field) }, "not a valid url".into()),
+            Self::Url(Some(scheme)) => {
+                let description = format!("not a valid `{}` url", scheme.value());
+                let condition = quote::quote! {
+                    vale::__private::Url::parse(&#field).map_or(false, |url| url.scheme() == #scheme)
+                };
+                (condition, description)
+            }
+            Self::Phone(false) => (
+                quote::quote! { vale::is_phone(&#field) },
+                "not a valid phone number".into(),
+            ),
+            Self::Phone(true) => (
+                quote::quote! { vale::is_e164(&#field) },
+                "not a valid E.164 phone number".into(),
+            ),
+            Self::Hex => (
+                quote::quote! { #field.len() % 2 == 0 && #field.chars().all(|c| c.is_ascii_hexdigit()) },
+                "not a valid hexadecimal string".into(),
+            ),
+            #[cfg(feature = "base64")]
+            Self::Base64 => (
+                quote::quote! { vale::is_base64(&#field) },
+                "not a valid base64 string".into(),
+            ),
+            #[cfg(feature = "uuid")]
+            Self::Uuid(None) => (quote::quote! { vale::is_uuid(&#field) }, "not a valid UUID".into()),
+            #[cfg(feature = "uuid")]
+            Self::Uuid(Some(version)) => (
+                quote::quote! { vale::is_uuid_version(&#field, #version) },
+                format!("not a valid version {} UUID", version),
+            ),
+            Self::CreditCard => (
+                quote::quote! { vale::luhn_valid(&#field) },
+                "not a valid card number".into(),
+            ),
+            Self::Future => (
+                quote::quote! { vale::is_future(&#field) },
+                "value is not in the future".into(),
+            ),
+            Self::Past => (quote::quote! { vale::is_past(&#field) }, "value is not in the past".into()),
+            Self::Ipv4 => (
+                quote::quote! { #field.parse::<core::net::Ipv4Addr>().is_ok() },
+                "not a valid IPv4 address".into(),
+            ),
+            Self::Ipv6 => (
+                quote::quote! { #field.parse::<core::net::Ipv6Addr>().is_ok() },
+                "not a valid IPv6 address".into(),
+            ),
+            Self::Ip => (
+                quote::quote! { #field.parse::<core::net::IpAddr>().is_ok() },
+                "not a valid IPv4 or IPv6 address".into(),
+            ),
+            Self::Alphanumeric => (
+                quote::quote! { #field.chars().all(char::is_alphanumeric) },
+                "value is not alphanumeric".into(),
+            ),
+            Self::AsciiAlphanumeric => (
+                quote::quote! { #field.chars().all(|c| c.is_ascii_alphanumeric()) },
+                "value contains characters other than ascii letters and digits".into(),
+            ),
+            Self::Slug => (
+                quote::quote! { vale::is_slug(&#field) },
+                "not a valid slug, which consists of lowercase letters and digits separated by single hyphens"
+                    .into(),
+            ),
+            Self::Ascii => (quote::quote! { #field.is_ascii() }, "value is not ascii".into()),
+            Self::Numeric => (
+                quote::quote! { #field.chars().all(char::is_numeric) },
+                "value is not numeric".into(),
+            ),
+            Self::Trimmed => (
+                quote::quote! { #field.trim().len() == #field.len() },
+                "value has leading or trailing whitespace".into(),
+            ),
+            Self::Required => (quote::quote! { #field.is_some() }, "value is required".into()),
+            Self::TryWith(stream) => return match message.map(|m| m.finish(field)) {
+                Some(message) => quote::quote! {
+                    if (#stream)(&mut #field).is_err() {
+                        errors.push(#message.into());
+                    }
+                },
+                None => quote::quote! {
+                    if let Err(e) = (#stream)(&mut #field) {
+                        errors.push(e.into());
+                    }
+                },
             },
-            Self::Gt(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name > #stream,
-                    format!("Failed to validate field `{}`, value too low", stringify!(#name)),
-                )
+            Self::Nested => return match message.map(|m| m.finish(field)) {
+                Some(message) => quote::quote! {
+                    if vale::Validate::validate(&mut #field).is_err() {
+                        errors.push(#message.into());
+                    }
+                },
+                None => quote::quote! {
+                    if let Err(nested) = vale::Validate::validate(&mut #field) {
+                        errors.extend(nested.into_iter().map(|e| format!("{}: {}", #name, e)));
+                    }
+                },
             },
-            Self::Neq(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name != #stream,
-                    format!("Failed to validate field `{}`, value not allowed", stringify!(#name)),
-                )
+            Self::Each(validations) => {
+                let depth = label.indices.len();
+                let index = quote::format_ident!("__vale_index_{}", depth);
+                let item = quote::format_ident!("__vale_item_{}", depth);
+                let inner = Index { ident: index.clone(), key: false };
+                let rules = Self::finish_each(validations, label, inner, &item, message, mutable);
+                let borrow = borrow(mutable);
+                return quote::quote! {
+                    for (#index, #item) in (#borrow #field).into_iter().enumerate() {
+                        #(#rules;)*
+                    }
+                };
+            }
+            Self::EachKey(validations) => {
+                let depth = label.indices.len();
+                let item = quote::format_ident!("__vale_item_{}", depth);
+                let inner = Index { ident: item.clone(), key: true };
+                let rules = Self::finish_each(validations, label, inner, &item, message, false);
+                return quote::quote! {
+                    for #item in #field.keys() {
+                        #(#rules;)*
+                    }
+                };
+            }
+            Self::EachValue(validations) => {
+                let depth = label.indices.len();
+                let key = quote::format_ident!("__vale_key_{}", depth);
+                let item = quote::format_ident!("__vale_item_{}", depth);
+                let inner = Index { ident: key.clone(), key: true };
+                let rules = Self::finish_each(validations, label, inner, &item, message, mutable);
+                let borrow = borrow(mutable);
+                return quote::quote! {
+                    for (#key, #item) in (#borrow #field).into_iter() {
+                        #(#rules;)*
+                    }
+                };
+            }
+            Self::Trim => return quote::quote! {
+                vale::Transform::trim(&mut #field);
             },
-            Self::LenLt(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name.len() < #stream,
-                    format!("Failed to validate field `{}`, value too long", stringify!(#name)),
-                )
+            Self::ToLowerCase => return quote::quote! {
+                vale::Transform::to_lower_case(&mut #field);
             },
-            Self::LenEq(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name.len ()== #stream,
-                    format!("Failed to validate field `{}`, value of incorrect length", stringify!(#name)),
-                )
+            Self::ToUpperCase => return quote::quote! {
+                vale::Transform::to_upper_case(&mut #field);
             },
-            Self::LenGt(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name.len() > #stream,
-                    format!("Failed to validate field `{}`, value too short", stringify!(#name)),
-                )
+            Self::Capitalize => return quote::quote! {
+                vale::Transform::capitalize(&mut #field);
             },
-            Self::LenNeq(stream) => quote::quote! {
-                vale::rule!(
-                    self.#name.len() != #stream,
-                    format!("Failed to validate field `{}`, value of disallowed length", stringify!(#name)),
-                )
+            Self::Truncate(stream) => return quote::quote! {
+                vale::Transform::truncate(&mut #field, #stream);
             },
-            Self::With(stream) => quote::quote! {
-                vale::rule!(
-                    #stream(&mut self.#name),
-                    format!("Failed to validate field `{}`, value did not pass test", stringify!(#name)),
-                )
+            Self::DefaultIfEmpty(stream) => return quote::quote! {
+                vale::Transform::default_if_empty(&mut #field, &#stream);
             },
-            Self::Trim => quote::quote! {
-                self.#name = self.#name.trim().into();
+        };
+        let message = match message {
+            Some(message) => message.finish(field),
+            None if label.show_values => quote::quote! {
+                format!("Failed to validate field `{}` = {:?}, {}", #name, #field, #description)
             },
-            Self::ToLowerCase => quote::quote! {
-                self.#name = self.#name.to_lowercase().into();
+            None if label.indices.is_empty() => {
+                let message = format!("Failed to validate field `{}`, {}", label.name, description);
+                syn::LitStr::new(&message, proc_macro2::Span::call_site()).to_token_stream()
+            }
+            None => quote::quote! {
+                format!("Failed to validate field `{}`, {}", #name, #description)
             },
+        };
+
+        quote::quote! {
+            vale::rule!(#condition, #message)
         }
     }
 }
@@ -15,8 +15,33 @@ pub fn rule(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
     ds.finish().into()
 }
 
+#[proc_macro]
+pub fn rule_warn(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ds = syn::parse_macro_input!(ts as rule::Rule);
+    ds.finish_warning().into()
+}
+
+#[proc_macro]
+pub fn rule_try(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ds = syn::parse_macro_input!(ts as rule::Rule);
+    ds.finish_try().into()
+}
+
+#[proc_macro]
+pub fn rule_into(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ds = syn::parse_macro_input!(ts as rule::RuleInto);
+    ds.finish().into()
+}
+
+#[proc_macro]
+pub fn try_transform(ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let ds = syn::parse_macro_input!(ts as rule::TryTransform);
+    ds.finish().into()
+}
+
 #[proc_macro_attribute]
-pub fn ruleset(_: proc_macro::TokenStream, ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+pub fn ruleset(attr: proc_macro::TokenStream, ts: proc_macro::TokenStream) -> proc_macro::TokenStream {
+    let options = syn::parse_macro_input!(attr as ruleset::Options);
     let ds = syn::parse_macro_input!(ts as ruleset::Ruleset);
-    ds.finish().into()
+    ds.finish(options).into()
 }
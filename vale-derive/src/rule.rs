@@ -3,42 +3,151 @@ use syn::{parse, punctuated as punct};
 pub(crate) struct Rule {
     condition: syn::Expr,
     msg: syn::Expr,
+    format_args: Vec<syn::Expr>,
 }
 
 impl parse::Parse for Rule {
     fn parse(input: parse::ParseStream) -> parse::Result<Self> {
         let span = proc_macro2::Span::call_site();
 
-        let mut content = 
+        let content = 
             punct::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?;
-        let args = content.len();
-        if args != 1 && args != 2 {
-            let msg = format!("`rule` macro requires 1 or 2 arguments, got {}", args);
-            return Err(parse::Error::new(span, &msg));
-        }
-
-        let msg = if args == 2 {
-            content.pop().unwrap().into_value()
-        } else {
+        let mut content = content.into_iter();
+        let condition = match content.next() {
+            Some(condition) => condition,
+            None => {
+                let msg = "`rule` macro requires at least 1 argument, got 0";
+                return Err(parse::Error::new(span, msg));
+            }
+        };
+        let msg = content.next().unwrap_or_else(|| {
             syn::Expr::Lit(syn::ExprLit {
                 attrs: vec![],
                 lit: syn::Lit::Str(syn::LitStr::new("No message provided", span)),
             })
+        });
+        let format_args: Vec<_> = content.collect();
+
+        Ok(Self { condition, msg, format_args })
+    }
+}
+
+/// The arguments of `rule_into`, which are those of `rule` preceded by the vector that the error is
+/// pushed to.
+pub(crate) struct RuleInto {
+    sink: syn::Expr,
+    rule: Rule,
+}
+
+impl parse::Parse for RuleInto {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        let sink = input.parse()?;
+        input.parse::<syn::Token![,]>()?;
+        Ok(Self { sink, rule: input.parse()? })
+    }
+}
+
+impl RuleInto {
+    pub(crate) fn finish(self) -> proc_macro2::TokenStream {
+        let sink = self.sink;
+        self.rule.finish_into(quote::quote! { (#sink) })
+    }
+}
+
+/// The arguments of `try_transform`, which are an assignment whose right hand side evaluates to a
+/// `Result`, optionally followed by a message and the arguments to format it with.
+pub(crate) struct TryTransform {
+    target: syn::Expr,
+    value: syn::Expr,
+    msg: Option<syn::Expr>,
+    format_args: Vec<syn::Expr>,
+}
+
+impl parse::Parse for TryTransform {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        let span = proc_macro2::Span::call_site();
+
+        let content =
+            punct::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?;
+        let mut content = content.into_iter();
+        let (target, value) = match content.next() {
+            Some(syn::Expr::Assign(assign)) => (*assign.left, *assign.right),
+            Some(expr) => {
+                let msg = "the first argument of `try_transform` must be an assignment, as in \
+                    `self.x = expr`";
+                return Err(parse::Error::new_spanned(expr, msg));
+            }
+            None => {
+                let msg = "`try_transform` macro requires at least 1 argument, got 0";
+                return Err(parse::Error::new(span, msg));
+            }
         };
+        let msg = content.next();
+        let format_args: Vec<_> = content.collect();
 
-        let condition = content.pop().unwrap().into_value();
+        Ok(Self { target, value, msg, format_args })
+    }
+}
 
-        Ok(Self { condition, msg, })
+impl TryTransform {
+    /// Creates the code that assigns the value when it is `Ok`, and pushes the error message when
+    /// it is not. Without a message, the error itself is displayed.
+    pub(crate) fn finish(self) -> proc_macro2::TokenStream {
+        let Self { target, value, msg, format_args } = self;
+        let msg = match msg {
+            None => quote::quote! { vale::__private::prelude::ToString::to_string(&__vale_error) },
+            Some(msg) if format_args.is_empty() => quote::quote! { { #msg }.into() },
+            Some(msg) => quote::quote! { vale::__private::prelude::format!(#msg, #(#format_args),*) },
+        };
+        quote::quote! {
+            match #value {
+                Ok(__vale_value) => #target = __vale_value,
+                Err(__vale_error) => errors.push(#msg),
+            }
+        }
     }
 }
 
 impl Rule {
     pub(crate) fn finish(self) -> proc_macro2::TokenStream {
-        let Self { condition, msg } = self;
+        self.finish_into(quote::quote! { errors })
+    }
+
+    /// Creates the code that records a warning when the condition does not hold.
+    pub(crate) fn finish_warning(self) -> proc_macro2::TokenStream {
+        self.finish_into(quote::quote! { warnings })
+    }
+
+    /// Creates the code for `rule_try`, whose condition evaluates to a `Result<bool, E>`. An `Err`
+    /// is recorded through its `Display` implementation, and `Ok(false)` records the message.
+    pub(crate) fn finish_try(self) -> proc_macro2::TokenStream {
+        let Self { condition, msg, format_args } = self;
+        let msg = if format_args.is_empty() {
+            quote::quote! { { #msg }.into() }
+        } else {
+            quote::quote! { vale::__private::prelude::format!(#msg, #(#format_args),*) }
+        };
+        quote::quote! {
+            match #condition {
+                Ok(true) => {}
+                Ok(false) => errors.push(#msg),
+                Err(__vale_error) => errors.push(vale::__private::prelude::ToString::to_string(&__vale_error)),
+            }
+        }
+    }
+
+    /// Creates the code that pushes the error to `sink` when the condition does not hold.
+    fn finish_into(self, sink: proc_macro2::TokenStream) -> proc_macro2::TokenStream {
+        let Self { condition, msg, format_args } = self;
+        let msg = if format_args.is_empty() {
+            quote::quote! { { #msg }.into() }
+        } else {
+            quote::quote! { vale::__private::prelude::format!(#msg, #(#format_args),*) }
+        };
         quote::quote! {
             if !{#condition} {
-                errors.push({ #msg }.into());
+                #sink.push(#msg);
             }
         }
     }
-}
\ No newline at end of file
+}
@@ -1,6 +1,9 @@
+use quote::ToTokens;
 use syn::{parse, punctuated as punct, token};
 
 pub(crate) struct Ruleset {
+    /// The attributes placed on the function besides `ruleset`, such as `#[inline]`.
+    attrs: Vec<syn::Attribute>,
     visibility: syn::Visibility,
     _fn_keyword: syn::Token![fn],
     name: syn::Ident,
@@ -15,6 +18,7 @@ impl parse::Parse for Ruleset {
     fn parse(input: parse::ParseStream) -> parse::Result<Self> {
         let args;
         Ok(Self { 
+            attrs: input.call(syn::Attribute::parse_outer)?,
             visibility: input.parse()?,
             _fn_keyword: input.parse()?,
             name: input.parse()?,
@@ -27,21 +31,125 @@ impl parse::Parse for Ruleset {
     }
 }
 
+/// The arguments that can be passed to the `ruleset` attribute, as in
+/// `#[vale::ruleset(fail_fast)]`.
+#[derive(Default)]
+pub(crate) struct Options {
+    /// Return as soon as a statement of the ruleset produced an error.
+    fail_fast: bool,
+    /// Return as soon as this many errors were produced, from `max_errors = n`.
+    max_errors: Option<syn::LitInt>,
+    /// Statements that run right before the function returns, on every path. These can't be passed
+    /// to the attribute, and are used by `#[derive(Validate)]` to log the number of errors.
+    exit: proc_macro2::TokenStream,
+}
+
+impl Options {
+    /// The options of the `validate` function that `#[derive(Validate)]` generates.
+    pub(crate) fn derived(max_errors: Option<syn::LitInt>, exit: proc_macro2::TokenStream) -> Self {
+        Self { fail_fast: false, max_errors, exit }
+    }
+}
+
+impl parse::Parse for Options {
+    fn parse(input: parse::ParseStream) -> parse::Result<Self> {
+        let mut options = Self::default();
+        let args = punct::Punctuated::<syn::Meta, syn::Token![,]>::parse_terminated(input)?;
+        for arg in args {
+            match arg {
+                syn::Meta::Path(path) if path.is_ident("fail_fast") => options.fail_fast = true,
+                syn::Meta::NameValue(nv) if nv.path.is_ident("max_errors") => {
+                    options.max_errors = Some(parse_max_errors(nv.lit)?);
+                }
+                otherwise => {
+                    let name = otherwise.path().to_token_stream();
+                    let msg = format!("unrecognised ruleset argument: {}", name);
+                    return Err(parse::Error::new_spanned(otherwise, msg));
+                }
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// Parses the value of `max_errors = n`, which must be a positive integer.
+pub(crate) fn parse_max_errors(lit: syn::Lit) -> parse::Result<syn::LitInt> {
+    let lit = match lit {
+        syn::Lit::Int(lit) => lit,
+        lit => return Err(parse::Error::new(lit.span(), "`max_errors` must be an integer")),
+    };
+    if lit.base10_parse::<usize>()? == 0 {
+        return Err(parse::Error::new(lit.span(), "`max_errors` must be at least 1"));
+    }
+    Ok(lit)
+}
+
 impl Ruleset {
-    pub(crate) fn finish(self) -> proc_macro2::TokenStream {
-        let Self { visibility, name, args, return_type, fn_body, .. } = self;
+    /// Whether the function returns its warnings besides its result, as
+    /// `Validate::validate_with_warnings` does. This is the case when it returns a tuple.
+    fn has_warnings(&self) -> bool {
+        matches!(self.return_type, syn::Type::Tuple(_))
+    }
+
+    pub(crate) fn finish(self, options: Options) -> proc_macro2::TokenStream {
+        let has_warnings = self.has_warnings();
+        let Self { attrs, visibility, name, args, return_type, fn_body, .. } = self;
         let syn::Block { stmts , .. } = fn_body;
         let args = args.into_iter();
         let stmts = stmts.into_iter();
+        let warnings = if has_warnings {
+            quote::quote! { let mut warnings = vale::__private::prelude::Vec::new(); }
+        } else {
+            proc_macro2::TokenStream::new()
+        };
+        let exit = options.exit;
+        // a function with warnings returns them next to its result
+        let finish = |result: proc_macro2::TokenStream| {
+            let result = if has_warnings {
+                quote::quote! { (#result, warnings) }
+            } else {
+                result
+            };
+            if exit.is_empty() {
+                result
+            } else {
+                quote::quote! {{ #exit #result }}
+            }
+        };
+        let mut check = proc_macro2::TokenStream::new();
+        if let Some(max) = options.max_errors {
+            // a statement may push several errors, so the ones past the cap are dropped
+            let result = finish(quote::quote! { Err(errors) });
+            check.extend(quote::quote! {
+                if errors.len() >= #max {
+                    errors.truncate(#max);
+                    return #result;
+                }
+            });
+        }
+        if options.fail_fast {
+            let result = finish(quote::quote! { Err(errors) });
+            check.extend(quote::quote! {
+                if !errors.is_empty() {
+                    return #result;
+                }
+            });
+        }
+        let result = finish(quote::quote! {
+            if errors.is_empty() {
+                Ok(())
+            } else {
+                Err(errors)
+            }
+        });
+        // `Vec::new` does not allocate, so a function whose rules all hold never touches the heap
         quote::quote!{
+            #(#attrs)*
             #visibility fn #name(#(#args, )*) -> #return_type {
-                let mut errors = Vec::new();
-                #(#stmts; )*;
-                if errors.len() != 0 {
-                    Err(errors)
-                } else {
-                    Ok(())
-                }
+                let mut errors = vale::__private::prelude::Vec::new();
+                #warnings
+                #(#stmts; #check)*;
+                #result
             }
         }
     }
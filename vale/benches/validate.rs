@@ -0,0 +1,72 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use vale::Validate;
+
+#[derive(vale::Validate)]
+struct Address {
+    #[validate(trim, len_gt(0))]
+    street: String,
+    #[validate(to_upper_case, len_eq(2))]
+    country: String,
+}
+
+#[derive(vale::Validate)]
+struct User {
+    #[validate(trim, to_lower_case, len_range(3, 20), ascii_alphanumeric)]
+    name: String,
+    #[validate(email)]
+    email: String,
+    #[validate(gte(18), lt(150))]
+    age: u32,
+    #[validate(each(gt(0)), max_items(5))]
+    scores: Vec<i32>,
+    #[validate(len_gt(0))]
+    nickname: Option<String>,
+    #[validate(nested)]
+    address: Address,
+}
+
+fn valid_user() -> User {
+    User {
+        name: "luuk".to_string(),
+        email: "luuk@example.com".to_string(),
+        age: 30,
+        scores: vec![1, 2, 3],
+        nickname: Some("cheese".to_string()),
+        address: Address { street: "Dam 1".to_string(), country: "NL".to_string() },
+    }
+}
+
+fn invalid_user() -> User {
+    let mut user = valid_user();
+    user.age = 12;
+    user.scores.push(0);
+    user.address.street.clear();
+    user
+}
+
+fn validate(c: &mut Criterion) {
+    let mut user = valid_user();
+    c.bench_function("validate valid", |b| b.iter(|| black_box(&mut user).validate()));
+    let mut user = invalid_user();
+    c.bench_function("validate invalid", |b| b.iter(|| black_box(&mut user).validate()));
+}
+
+fn validate_fields(c: &mut Criterion) {
+    let mut user = valid_user();
+    c.bench_function("validate_fields valid", |b| b.iter(|| black_box(&mut user).validate_fields()));
+    let mut user = invalid_user();
+    c.bench_function("validate_fields invalid", |b| b.iter(|| black_box(&mut user).validate_fields()));
+}
+
+fn transform(c: &mut Criterion) {
+    let mut name = "luuk".to_string();
+    c.bench_function("trim unchanged", |b| b.iter(|| vale::Transform::trim(black_box(&mut name))));
+    c.bench_function("to_lower_case unchanged", |b| {
+        b.iter(|| vale::Transform::to_lower_case(black_box(&mut name)))
+    });
+}
+
+criterion_group!(benches, validate, validate_fields, transform);
+criterion_main!(benches);
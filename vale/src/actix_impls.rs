@@ -0,0 +1,123 @@
+use actix_web::dev::Payload;
+use actix_web::http::StatusCode;
+use actix_web::web::Json;
+use actix_web::{FromRequest, HttpRequest, HttpResponse, ResponseError};
+use crate::{ErrorBody, ResponseFormat};
+use serde::de::DeserializeOwned;
+use std::fmt;
+use std::future::Future;
+use std::ops::Deref;
+use std::ops::DerefMut;
+use std::pin::Pin;
+
+/// A struct that can be used as an extractor in `actix-web` handlers. If you have some type that
+/// implements both `Validate` and `Deserialize`, you can designate in your handler that you want to
+/// have a validated instance of that type. The request body is parsed as JSON.
+///
+/// ### Example
+/// ```rust
+/// # #[derive(vale::Validate, serde::Deserialize)]
+/// # struct User {}
+/// use vale::actix::Valid;
+///
+/// async fn update_user(user: Valid<User>) -> &'static str {
+///     // user is now validated, this code is not reached if the validation failed
+///     "ok"
+/// }
+///
+/// let app = actix_web::App::new().route("/user", actix_web::web::post().to(update_user));
+/// ```
+///
+/// If the validation fails, the request is rejected with a `400 Bad Request` response, whose body
+/// is a JSON array of the validation errors. A different `vale::ResponseFormat` can be registered
+/// as app data, using `App::app_data(vale::ResponseFormat::Text)`.
+///
+/// ### Features
+/// Requires the `actix` feature to be enabled
+pub struct Valid<T> {
+    data: T,
+}
+
+impl<T: crate::Validate> Valid<T> {
+    fn new(t: T) -> Self {
+        Self {
+            data: t,
+        }
+    }
+
+    /// Consumes the `Valid` wrapper and returns the inner item.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T: crate::Validate> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: crate::Validate> DerefMut for Valid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+/// The error that is returned by the `Valid` extractor when the extracted data did not pass
+/// validation. It is turned into a `400 Bad Request` response containing the errors.
+#[derive(Debug)]
+pub struct ValidationError {
+    body: ErrorBody,
+}
+
+impl ValidationError {
+    /// Returns the errors that were triggered during validation.
+    pub fn errors(&self) -> &[String] {
+        self.body.errors()
+    }
+
+    /// Returns the body of the response, in the configured `ResponseFormat`.
+    pub fn body(&self) -> &ErrorBody {
+        &self.body
+    }
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "validation failed: {}", self.errors().join(", "))
+    }
+}
+
+impl ResponseError for ValidationError {
+    fn status_code(&self) -> StatusCode {
+        StatusCode::BAD_REQUEST
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        HttpResponse::build(self.status_code())
+            .content_type(self.body.content_type())
+            .body(self.body.body())
+    }
+}
+
+impl<T> FromRequest for Valid<T>
+where
+    T: DeserializeOwned + crate::Validate + 'static,
+{
+    type Error = actix_web::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self, Self::Error>>>>;
+
+    fn from_request(req: &HttpRequest, payload: &mut Payload) -> Self::Future {
+        let format = req.app_data::<ResponseFormat>().copied().unwrap_or_default();
+        let json = Json::<T>::from_request(req, payload);
+        Box::pin(async move {
+            let mut inner = json.await?.into_inner();
+            if let Err(body) = format.validate(&mut inner) {
+                return Err(ValidationError { body }.into());
+            }
+            Ok(Valid::new(inner))
+        })
+    }
+}
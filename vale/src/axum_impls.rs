@@ -0,0 +1,122 @@
+use axm::extract::rejection::JsonRejection;
+use axm::extract::{FromRequest, Request};
+use axm::http::{header, StatusCode};
+use axm::response::{IntoResponse, Response};
+use axm::Json;
+use crate::{ErrorBody, ResponseFormat};
+use serde::de::DeserializeOwned;
+use std::ops::Deref;
+use std::ops::DerefMut;
+
+/// A struct that can be used as an extractor in `axum` handlers. If you have some type that
+/// implements both `Validate` and `Deserialize`, you can designate in your handler that you want to
+/// have a validated instance of that type. The request body is parsed as JSON, using `axum::Json`.
+///
+/// ### Example
+/// ```rust
+/// # extern crate axm as axum;
+/// # #[derive(vale::Validate, serde::Deserialize)]
+/// # struct User {}
+/// use vale::axum::Valid;
+///
+/// async fn update_user(user: Valid<User>) {
+///     // user is now validated, this code is not reached if the validation failed
+/// }
+///
+/// let app: axum::Router = axum::Router::new().route("/user", axum::routing::post(update_user));
+/// ```
+///
+/// If the body can not be parsed, or if the validation fails, the request is rejected with a
+/// `ValidationRejection`.
+///
+/// ### Features
+/// Requires the `axum` feature to be enabled
+pub struct Valid<T> {
+    data: T,
+}
+
+impl<T: crate::Validate> Valid<T> {
+    fn new(t: T) -> Self {
+        Self {
+            data: t,
+        }
+    }
+
+    /// Consumes the `Valid` wrapper and returns the inner item.
+    pub fn into_inner(self) -> T {
+        self.data
+    }
+}
+
+impl<T: crate::Validate> Deref for Valid<T> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<T: crate::Validate> DerefMut for Valid<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.data
+    }
+}
+
+/// The rejection that is returned by the `Valid` extractor. When converted into a response, a
+/// `JsonError` produces the same response as `axum::Json` would, while a `ValidationError` results
+/// in a `400 Bad Request` with a JSON array of the validation errors as the body. The format of the
+/// body can be changed by adding a `vale::ResponseFormat` to the request extensions, in which case
+/// the validation errors are returned as a `FormattedError` instead:
+///
+/// ```rust
+/// # extern crate axm as axum;
+/// # #[derive(vale::Validate, serde::Deserialize)]
+/// # struct User {}
+/// # async fn update_user(user: vale::axum::Valid<User>) {}
+/// let app: axum::Router = axum::Router::new()
+///     .route("/user", axum::routing::post(update_user))
+///     .layer(axum::Extension(vale::ResponseFormat::JsonObject));
+/// ```
+#[derive(Debug)]
+pub enum ValidationRejection {
+    /// The body could not be parsed as JSON.
+    JsonError(JsonRejection),
+    /// The body was parsed, but did not pass validation.
+    ValidationError(Vec<String>),
+    /// The body was parsed, but did not pass validation, and a `vale::ResponseFormat` was added to
+    /// the request extensions. The errors are sent in that format.
+    FormattedError(ErrorBody),
+}
+
+impl IntoResponse for ValidationRejection {
+    fn into_response(self) -> Response {
+        match self {
+            Self::JsonError(rejection) => rejection.into_response(),
+            Self::ValidationError(errors) => (StatusCode::BAD_REQUEST, Json(errors)).into_response(),
+            Self::FormattedError(body) => {
+                let content_type = [(header::CONTENT_TYPE, body.content_type())];
+                (StatusCode::BAD_REQUEST, content_type, body.body()).into_response()
+            }
+        }
+    }
+}
+
+impl<S, T> FromRequest<S> for Valid<T>
+where
+    S: Send + Sync,
+    T: DeserializeOwned + crate::Validate,
+{
+    type Rejection = ValidationRejection;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let format = req.extensions().get::<ResponseFormat>().copied();
+        let Json(mut inner) = Json::<T>::from_request(req, state)
+            .await
+            .map_err(ValidationRejection::JsonError)?;
+        match format {
+            Some(format) => format.validate(&mut inner).map_err(ValidationRejection::FormattedError)?,
+            None => inner.validate().map_err(ValidationRejection::ValidationError)?,
+        }
+        Ok(Valid::new(inner))
+    }
+}
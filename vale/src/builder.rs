@@ -0,0 +1,51 @@
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// A builder that collects the errors of a number of rules, for when validation is written without
+/// the `ruleset` and `rule` macros. Like `#[vale::ruleset]`, every rule is checked, and the errors
+/// are returned in the order in which the rules were added.
+///
+/// ### Example
+/// ```rust
+/// struct Entity {
+///     id: i32,
+///     others: Vec<i32>,
+/// }
+///
+/// impl vale::Validate for Entity {
+///     fn validate(&mut self) -> vale::Result {
+///         vale::Validator::new()
+///             .rule(self.id > 0, "`id` is nonpositive!")
+///             .rule(self.others.len() < 5, format!("{} others is too many", self.others.len()))
+///             .finish()
+///     }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Validator {
+    errors: Vec<String>,
+}
+
+impl Validator {
+    /// Creates a validator without any errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a rule. When `condition` is `false`, `msg` is added to the errors.
+    pub fn rule(mut self, condition: bool, msg: impl Into<String>) -> Self {
+        if !condition {
+            self.errors.push(msg.into());
+        }
+        self
+    }
+
+    /// Returns `Ok(())` if every rule passed, and the collected errors otherwise.
+    pub fn finish(self) -> crate::Result {
+        if self.errors.is_empty() {
+            Ok(())
+        } else {
+            Err(self.errors)
+        }
+    }
+}
@@ -0,0 +1,108 @@
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+
+/// The errors of a validation, grouped by the field that produced them. This is returned by
+/// `Validate::validate_fields`. Errors that do not belong to a single field, such as those of
+/// validations placed on the struct itself, are stored under the empty key `""`. The fields are
+/// kept in the order in which they first produced an error, which for `#[derive(Validate)]` is the
+/// order in which they are declared.
+///
+/// When the `serde` feature is enabled, this type serializes to an object that maps each field to
+/// its errors, for example `{ "name": ["msg1", "msg2"] }`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FieldErrors {
+    errors: Vec<(String, Vec<String>)>,
+}
+
+impl FieldErrors {
+    /// Creates an empty set of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the provided errors to those of `field`. Fields without errors are not stored.
+    pub fn extend(&mut self, field: &str, errors: impl IntoIterator<Item = String>) {
+        let mut errors = errors.into_iter().peekable();
+        if errors.peek().is_none() {
+            return;
+        }
+        match self.errors.iter_mut().find(|(key, _)| key == field) {
+            Some((_, existing)) => existing.extend(errors),
+            None => self.errors.push((field.to_string(), errors.collect())),
+        }
+    }
+
+    /// Returns the errors of `field`, if it has any.
+    pub fn get(&self, field: &str) -> Option<&[String]> {
+        self.errors.iter().find(|(key, _)| key == field).map(|(_, errors)| errors.as_slice())
+    }
+
+    /// Returns `true` if no field has any errors.
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    /// Iterates over the fields that have errors, in the order in which they were added.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &[String])> {
+        self.errors.iter().map(|(field, errors)| (field.as_str(), errors.as_slice()))
+    }
+}
+
+/// Flattens the errors into a single list, in the order of the fields.
+impl From<FieldErrors> for Vec<String> {
+    fn from(errors: FieldErrors) -> Self {
+        errors.errors.into_iter().flat_map(|(_, errors)| errors).collect()
+    }
+}
+
+/// Groups a flat list of errors by the field that they mention, for when only the result of
+/// `Validate::validate` is available. Prefer `Validate::validate_fields`, which groups the errors
+/// without looking at their messages.
+///
+/// The field is taken from the default messages of `#[derive(Validate)]`, as in ``Failed to
+/// validate field `name`, ...``. Elements are grouped with their collection, so `tags[2]` belongs
+/// to `tags`. All other errors, such as custom messages and the errors of `nested` values, are
+/// stored under the empty key `""`. Note that a field that uses `rename = "..."` is grouped under
+/// its new name.
+///
+/// ### Example
+/// ```rust
+/// let errors = vec![
+///     "Failed to validate field `name`, value too long".to_string(),
+///     "Failed to validate field `tags[1]`, value too short".to_string(),
+///     "Passwords do not match".to_string(),
+/// ];
+/// let grouped = vale::group_by_field(errors);
+/// assert_eq!(grouped["name"], ["Failed to validate field `name`, value too long"]);
+/// assert_eq!(grouped["tags"], ["Failed to validate field `tags[1]`, value too short"]);
+/// assert_eq!(grouped[""], ["Passwords do not match"]);
+/// ```
+///
+/// ### Features
+/// Requires the `std` feature to be enabled
+#[cfg(feature = "std")]
+pub fn group_by_field(
+    errors: impl IntoIterator<Item = String>,
+) -> std::collections::HashMap<String, Vec<String>> {
+    let mut grouped = std::collections::HashMap::<String, Vec<String>>::new();
+    for error in errors {
+        grouped.entry(mentioned_field(&error).to_string()).or_default().push(error);
+    }
+    grouped
+}
+
+/// Returns the field that the error message mentions, or `""` if it mentions none.
+#[cfg(feature = "std")]
+fn mentioned_field(error: &str) -> &str {
+    match error.strip_prefix("Failed to validate field `") {
+        Some(rest) => rest.split(['`', '[']).next().unwrap_or_default(),
+        None => "",
+    }
+}
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for FieldErrors {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(self.errors.iter().map(|(field, errors)| (field, errors)))
+    }
+}
@@ -1,11 +1,13 @@
 #![cfg_attr(feature = "rocket", feature(decl_macro, proc_macro_hygiene))]
+#![cfg_attr(not(feature = "std"), no_std)]
 #![forbid(unsafe_code, missing_docs)]
 
 //! Vale stands for Valid Entity, and is a simple library that provides entity validation through
 //! either annotations, or through a Fluent-style implementation. At the core of the library is the
 //! `vale::Validate` trait, which implies that a piece of data can be validated. The library also
-//! offers supoort for the `rocket` webframework. If support for more webframeworks is desired, it
-//! should be fairly trivial to implement support for those frameworks.
+//! offers supoort for the `rocket`, `actix-web`, `axum` and `warp` webframeworks, and for `tower`
+//! services. If support for more webframeworks is desired, it should be fairly trivial to implement
+//! support for those frameworks.
 //!
 //! ### Example
 //! This example shows how to derive the validation trait
@@ -55,12 +57,134 @@
 //!     }
 //! }
 //! ```
+//!
+//! Or, without any macros, using `vale::Validator`:
+//! ```rust
+//! # struct Entity {
+//! #     id: i32,
+//! #     others: Vec<i32>,
+//! # }
+//! impl vale::Validate for Entity {
+//!     fn validate(&mut self) -> vale::Result {
+//!         vale::Validator::new()
+//!             .rule(self.id > 0, "`id` is nonpositive!")
+//!             .rule(self.others.len() < 5, "Too many others")
+//!             .finish()
+//!     }
+//! }
+//! ```
+//!
+//! ### `no_std`
+//! The `Validate` trait, the macros and the derive only need `alloc`, so they can be used in
+//! `no_std` crates by disabling the default `std` feature. The web framework integrations and the
+//! `regex`, `url`, `chrono` and `tracing` features require `std`.
+
+extern crate alloc;
+
+use alloc::boxed::Box;
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
 
 #[cfg(feature = "rocket")]
 mod rocket_impls;
 
 #[cfg(feature = "rocket")]
-pub use rocket_impls::Valid;
+pub use rocket_impls::{
+    validation_error_body, validation_errors, Valid, ValidStream, ValidationError,
+};
+
+#[cfg(feature = "actix")]
+mod actix_impls;
+
+/// Support for the `actix-web` webframework.
+///
+/// ### Features
+/// Requires the `actix` feature to be enabled
+#[cfg(feature = "actix")]
+pub mod actix {
+    pub use crate::actix_impls::{Valid, ValidationError};
+}
+
+#[cfg(feature = "axum")]
+mod axum_impls;
+
+/// Support for the `axum` webframework.
+///
+/// ### Features
+/// Requires the `axum` feature to be enabled
+#[cfg(feature = "axum")]
+pub mod axum {
+    pub use crate::axum_impls::{Valid, ValidationRejection};
+}
+
+#[cfg(feature = "warp")]
+mod warp_impls;
+
+/// Support for the `warp` webframework.
+///
+/// ### Features
+/// Requires the `warp` feature to be enabled
+#[cfg(feature = "warp")]
+pub mod warp {
+    pub use crate::warp_impls::{recover, validated_json, validated_json_with, ValidationRejection};
+}
+
+#[cfg(feature = "tower")]
+mod tower_impls;
+
+/// Support for services built with `tower`, such as those of `hyper`.
+///
+/// ### Features
+/// Requires the `tower` feature to be enabled
+#[cfg(feature = "tower")]
+pub mod tower {
+    pub use crate::tower_impls::{ResponseFuture, ValidateLayer, ValidateService};
+}
+
+mod builder;
+mod errors;
+#[cfg(any(
+    feature = "rocket",
+    feature = "actix",
+    feature = "axum",
+    feature = "warp",
+    feature = "tower"
+))]
+mod response;
+mod transformers;
+mod validators;
+#[cfg(feature = "chrono")]
+mod time;
+
+pub use builder::Validator;
+pub use errors::FieldErrors;
+#[cfg(feature = "std")]
+pub use errors::group_by_field;
+#[cfg(any(
+    feature = "rocket",
+    feature = "actix",
+    feature = "axum",
+    feature = "warp",
+    feature = "tower"
+))]
+pub use response::{ErrorBody, ResponseFormat};
+
+pub use transformers::{capitalize, truncate, Transform};
+pub use validators::{is_email, is_slug, luhn_valid};
+#[cfg(feature = "regex")]
+pub use validators::regex_matches;
+#[cfg(feature = "url")]
+pub use validators::is_url;
+#[cfg(feature = "phone")]
+pub use validators::{is_e164, is_phone};
+#[cfg(feature = "base64")]
+pub use validators::is_base64;
+#[cfg(feature = "uuid")]
+pub use validators::{is_uuid, is_uuid_version};
+#[cfg(feature = "chrono")]
+pub use time::{is_future, is_future_at, is_past, is_past_at, Moment};
+
 /// The rule macro is used to create new rules that dictate how a field of the validated entity
 /// should be tranformed and validated.
 ///
@@ -76,29 +200,355 @@ pub use rocket_impls::Valid;
 ///         vale::rule!(self.a == 3, "A was not three!");
 ///         // if the second argument is omitted, a standard error message is returned.
 ///         vale::rule!(self.a % 3 == 0);
+///         // any further arguments are used to format the message, like `format!` does.
+///         vale::rule!(self.a < 10, "A was {}, which is too high", self.a);
 ///     }
 /// } 
 /// ```
 pub use vale_derive::rule;
+/// Works like `vale::rule`, but records an advisory warning instead of an error. Warnings do not
+/// make the validation fail. They are collected by a function annotated with `vale::ruleset` that
+/// returns a tuple of the result and the warnings, like `Validate::validate_with_warnings` does.
+///
+/// ### Example
+/// ```rust
+/// struct Password {
+///     value: String,
+/// }
+///
+/// impl vale::Validate for Password {
+///     fn validate(&mut self) -> vale::Result {
+///         self.validate_with_warnings().0
+///     }
+///
+///     #[vale::ruleset]
+///     fn validate_with_warnings(&mut self) -> (vale::Result, Vec<String>) {
+///         vale::rule!(self.value.len() >= 8, "Password too short");
+///         vale::rule_warn!(self.value.len() >= 12, "Consider a longer password");
+///     }
+/// }
+///
+/// let mut password = Password { value: "correct horse".to_string() };
+/// assert_eq!(vale::Validate::validate_with_warnings(&mut password), (Ok(()), vec![]));
+/// password.value.truncate(9);
+/// assert_eq!(
+///     vale::Validate::validate_with_warnings(&mut password),
+///     (Ok(()), vec!["Consider a longer password".to_string()]),
+/// );
+/// ```
+pub use vale_derive::rule_warn;
+/// Works like `vale::rule`, but pushes the error to the vector that is passed as the first
+/// argument, instead of to the one created by `vale::ruleset`. This allows rules to be used in any
+/// function.
+///
+/// ### Example
+/// ```rust
+/// fn check_tags(tags: &[String], errors: &mut Vec<String>) {
+///     for tag in tags {
+///         vale::rule_into!(errors, !tag.is_empty(), "Empty tag");
+///         vale::rule_into!(errors, tag.len() < 10, "Tag `{}` is too long", tag);
+///     }
+/// }
+///
+/// let mut errors = Vec::new();
+/// check_tags(&["".to_string(), "rust".to_string()], &mut errors);
+/// assert_eq!(errors, vec!["Empty tag".to_string()]);
+/// ```
+pub use vale_derive::rule_into;
+/// Works like `vale::rule`, but for a condition that can fail itself, and therefore evaluates to a
+/// `Result<bool, E>`. If it is `Ok(false)`, the message is added to the errors, like with
+/// `vale::rule`. If it is an `Err`, the error is added instead, through its `Display`
+/// implementation.
+///
+/// ### Example
+/// ```rust
+/// struct Form {
+///     age: String,
+/// }
+///
+/// impl vale::Validate for Form {
+///     #[vale::ruleset]
+///     fn validate(&mut self) -> vale::Result {
+///         vale::rule_try!(
+///             self.age.parse::<u32>().map(|age| age >= 18).map_err(|_| "Age is not a number"),
+///             "Too young",
+///         );
+///     }
+/// }
+///
+/// let mut form = Form { age: "twelve".to_string() };
+/// assert_eq!(vale::Validate::validate(&mut form), Err(vec!["Age is not a number".to_string()]));
+/// form.age = "12".to_string();
+/// assert_eq!(vale::Validate::validate(&mut form), Err(vec!["Too young".to_string()]));
+/// ```
+pub use vale_derive::rule_try;
+/// Performs a transformation that can fail, inside a function annotated with `vale::ruleset`. The
+/// first argument is an assignment whose right hand side evaluates to a `Result`. If it is `Ok`,
+/// the value is assigned. Otherwise, the message is added to the errors and the target is left
+/// untouched. Like with `vale::rule`, further arguments are used to format the message. If the
+/// message is omitted, the error itself is used, through its `Display` implementation.
+///
+/// ### Example
+/// ```rust
+/// struct Form {
+///     age_input: String,
+///     age: u32,
+/// }
+///
+/// impl vale::Validate for Form {
+///     #[vale::ruleset]
+///     fn validate(&mut self) -> vale::Result {
+///         vale::try_transform!(
+///             self.age = self.age_input.parse(),
+///             "`{}` is not an age",
+///             self.age_input
+///         );
+///         vale::rule!(self.age < 150, "Too old");
+///     }
+/// }
+///
+/// let mut form = Form { age_input: "42".to_string(), age: 0 };
+/// vale::Validate::validate(&mut form).unwrap();
+/// assert_eq!(form.age, 42);
+/// ```
+pub use vale_derive::try_transform;
 /// Use this macro to annotate yout implementation of `vale::Validate` for your struct to help
 /// write the error reporting boilerplate for you. See the documentation of `vale::rule` for usage
 /// examples.
+///
+/// By default every rule is checked and all errors are returned. Use `#[vale::ruleset(fail_fast)]`
+/// to return as soon as a statement of the function produced an error, in which case the remaining
+/// statements are not executed.
+/// Use `#[vale::ruleset(max_errors = 10)]` to return as soon as a statement brought the number of
+/// errors to at least 10. A statement such as a loop can produce several errors at once, so the
+/// errors past the cap are dropped, and exactly 10 are returned.
+///
+/// When the function returns a tuple, as in `-> (vale::Result, Vec<String>)`, the warnings recorded
+/// with `vale::rule_warn` are returned as its second element.
+///
+/// ### Example
+/// ```rust
+/// struct MyStruct {
+///     items: Vec<i32>,
+/// }
+///
+/// impl vale::Validate for MyStruct {
+///     #[vale::ruleset(fail_fast)]
+///     fn validate(&mut self) -> vale::Result {
+///         vale::rule!(!self.items.is_empty(), "No items");
+///         // not executed when `items` is empty
+///         vale::rule!(self.items[0] > 0, "First item is nonpositive");
+///     }
+/// }
+/// ```
 pub use vale_derive::ruleset;
-/// A proc macro used to implement `Validate` automatically for a struct.
-/// 
+/// A proc macro used to implement `Validate` automatically for a struct or an enum. For enums, the
+/// fields of the variant that is present are validated.
+///
 /// There are a couple of options for validating a structure. The are listed below:
 ///
 /// * `lt`: Check if the value is less than the provided argument,
 /// * `eq`: check if the value is equal to the provided argument,
 /// * `gt`: check if the value is greater than the provided argument,
-/// * `neq`: check if the `len()` of the value is not equal to the provided argument,
+/// * `neq` or `ne`: check if the value is not equal to the provided argument,
+/// * `lte`: check if the value is less than or equal to the provided argument,
+/// * `gte`: check if the value is greater than or equal to the provided argument,
+/// * `positive`: check if the value is greater than zero, where zero is the `Default` of its type,
+/// * `negative`: check if the value is less than zero,
+/// * `non_zero`: check if the value is not zero,
+/// * `divisible_by`: check if the value is a multiple of the provided argument,
 /// * `len_lt`: Check if the `len()` of the value is less than the provided argument,
 /// * `len_eq`: check if the `len()` of the value is equal to the provided argument,
 /// * `len_gt`: check if the `len()` of the value is greater than the provided argument,
 /// * `len_neq`: check if the `len()` of the value is not equal to the provided argument,
-/// * `with`: Rrn the provided function to perform validation,
+/// * `len_range`: check if the `len()` of the value lies between the two provided arguments,
+///   bounds included,
+/// * the length validations above measure a value with `len()`, which is the number of bytes for
+///   strings. Pass `count = "chars"` to count characters instead, for example
+///   `len_lt(10, count = "chars")`. With the `graphemes` feature, `count = "graphemes"` counts
+///   grapheme clusters, so that a character with combining marks or an emoji such as 👨‍👩‍👧 counts
+///   as one. `grapheme_len_lt`, `grapheme_len_eq`, `grapheme_len_gt`, `grapheme_len_neq` and
+///   `grapheme_len_range` are shorthands for this. To make explicit that bytes are counted, use
+///   `count = "bytes"` or the shorthands `bytes_len_lt`, `bytes_len_eq`, `bytes_len_gt`,
+///   `bytes_len_neq` and `bytes_len_range`. These work on anything that implements `AsRef<[u8]>`,
+///   such as strings and `Vec<u8>`,
+/// * `not_empty`: check if the value is not empty, using its `is_empty()`. This works for strings
+///   and collections alike,
+/// * `min_items`: check if the collection contains at least the provided number of items,
+/// * `max_items`: check if the collection contains at most the provided number of items,
+/// * `unique_items`: check if no two items of the collection are equal, which requires the items
+///   to implement `PartialEq`,
+/// * `matches_field`: check if the value is equal to the value of the provided field of the same
+///   struct, for example `matches_field(password)` on a `password_confirm` field,
+/// * `len_eq_field`: check if the `len()` of the value is equal to that of the provided field of
+///   the same struct, for example `len_eq_field(values)` on a `checksums` field,
+/// * `parse_into`: parse the value with `str::parse`, and assign the result to the provided field
+///   of the same struct. This allows a form to submit a number as a `String` field, for example
+///   `#[validate(parse_into(age))] age_input: String`, which then fills in `age: u32`,
+/// * `range`: check if the value lies between the two provided arguments, bounds included,
+/// * `range_exclusive`: check if the value lies between the two provided arguments, bounds
+///   excluded,
+/// * `one_of`: check if the value is equal to one of the provided arguments,
+/// * `not_one_of`: check if the value is not equal to any of the provided arguments,
+/// * `contains`: check if the value contains the provided pattern,
+/// * `starts_with`: check if the value starts with the provided pattern,
+/// * `ends_with`: check if the value ends with the provided pattern,
+/// * `starts_with_any`: check if the value starts with any of the provided patterns, for example
+///   `starts_with_any("http://", "https://")`,
+/// * `ends_with_any`: check if the value ends with any of the provided patterns,
+/// * `eq_ignore_case`: check if the value is equal to the provided string, ignoring the case of
+///   ascii letters, for example `eq_ignore_case("US")` accepts `"us"`,
+/// * `with`: Rrn the provided function to perform validation. This can be a path, such as
+///   `checks::is_even`, or a closure, such as `with(|x: &mut i32| *x > 0)`,
+/// * `with_args`: like `with`, but passes the remaining arguments to the function after the value,
+///   so `with_args(in_range, 1, 10)` calls `in_range(&mut value, 1, 10)`,
+/// * `try_with`: run the provided function to perform validation. The function returns a
+///   `Result<(), String>`, and the error it returns is used as the error message. Since the
+///   function receives a mutable reference, it can also convert the value, and report why that
+///   failed,
+/// * `predicate`: check if the provided boolean expression holds, for example
+///   `predicate = "self.start < self.end"`. The expression is written in a string literal and can
+///   refer to any field through `self`, so it can't be used on the fields of an enum. Use
+///   `predicate("...", message = "...")` to replace the error message,
+/// * `regex`: check if the value matches the provided regular expression. The pattern is checked
+///   at compile time and compiled only once at runtime. To use a pattern that is only known at
+///   runtime, such as one stored in another field, use `vale::regex_matches` with `predicate`.
+///   Requires the `regex` feature,
+/// * `email`: check if the value is a valid email address, see `vale::is_email`,
+/// * `url`: check if the value is an absolute url, see `vale::is_url`. Use `url(scheme = "https")`
+///   to also require a specific scheme. Requires the `url` feature,
+/// * `phone`: check if the value looks like a phone number, see `vale::is_phone`. Use
+///   `phone(format = "e164")` to require the E.164 format instead, see `vale::is_e164`. Requires
+///   the `phone` feature,
+/// * `hex`: check if the value is a hexadecimal string of even length, such as a hash,
+/// * `base64`: check if the value is valid base64, see `vale::is_base64`. Requires the `base64`
+///   feature,
+/// * `uuid`: check if the value is a UUID, see `vale::is_uuid`. Use `uuid(version = 4)` to also
+///   require a specific version, see `vale::is_uuid_version`. Requires the `uuid` feature,
+/// * `future`: check if the value lies in the future, see `vale::is_future`. Works for the
+///   `DateTime`, `NaiveDateTime` and `NaiveDate` types of `chrono`. Requires the `chrono` feature,
+/// * `past`: check if the value lies in the past, see `vale::is_past`. Requires the `chrono`
+///   feature,
+/// * `credit_card`: check if the value is a card number of 12 to 19 digits that passes the Luhn
+///   checksum, see `vale::luhn_valid`,
+/// * `ipv4`: check if the value is an IPv4 address, such as `127.0.0.1`,
+/// * `ipv6`: check if the value is an IPv6 address, such as `::1`,
+/// * `ip`: check if the value is either an IPv4 or an IPv6 address,
+/// * `alphanumeric`: check if the value only contains letters and digits. Like `ascii` and
+///   `numeric`, this accepts the empty string, so combine it with `len_gt(0)` to reject that,
+/// * `ascii_alphanumeric`: check if the value only contains ascii letters and digits, which suits
+///   machine identifiers,
+/// * `slug`: check if the value is a url slug, such as `my-post-1`, see `vale::is_slug`,
+/// * `ascii`: check if the value only contains ascii characters,
+/// * `numeric`: check if the value only contains digits,
+/// * `trimmed`: check if the value has no leading or trailing whitespace. Unlike `trim`, this
+///   rejects such values instead of fixing them,
+/// * `required`: check that the value is not `None`, only allowed on fields of type `Option<T>`,
+/// * `nested`: validate the value through its own implementation of `vale::Validate`. Each of
+///   the returned errors is prefixed with the name of the field, for example `address: ...`. In
+///   `validate_fields`, the errors are stored under their dotted path, such as `address.street`,
+///   see `Validate::validate_nested`,
+/// * `each`: apply the provided validations to every element of the value, for example
+///   `each(len_lt(20), trim)`. Errors mention the index of the failing element, such as `tags[2]`,
+/// * `each_value`: apply the provided validations to every value of a map, such as a `HashMap` or a
+///   `BTreeMap`, for example `each_value(gt(0))`. Errors mention the key of the failing value
+///   through its `Debug` implementation, such as `settings["timeout"]`,
+/// * `each_key`: apply the provided validations to every key of a map. Since keys can't be
+///   modified, transformers and `with` can't be used here,
 /// * `trim`: always succeeds, and trims the string that is inputted,
-/// * `to_lower_case`: convert the provided value to lowercase.
+/// * `to_lower_case`: convert the provided value to lowercase,
+/// * `to_upper_case`: convert the provided value to uppercase,
+/// * `capitalize`: convert the first character of the provided value to uppercase, see
+///   `vale::capitalize`,
+/// * `truncate`: shorten the provided value to at most the provided number of characters, see
+///   `vale::truncate`,
+/// * `default_if_empty`: replace the provided value with the provided argument if it is empty, for
+///   example `default_if_empty("N/A")`.
+///
+/// The transformers can be used on the types that implement `vale::Transform`, such as `String`,
+/// `Box<str>` and `Arc<String>`. Using them on another type results in a compile error that says
+/// that `Transform` is not implemented for it. Borrowed types such as `&str` are not supported,
+/// since the transformed value can't borrow from the field it replaces.
+///
+/// Each of these validations produces a default error message. This message can be replaced by
+/// passing a `message` to the validation, for example `gt(0, message = "id must be positive")` or
+/// `email(message = "not an email")`. The message is used as-is. To create the message from the
+/// value instead, pass the path of a function as `message_fn`, for example
+/// `gt(0, message_fn = describe_id)`. The function receives a reference to the value and returns
+/// a `String`.
+///
+/// Generic structs and enums are supported. The bounds that the validations need, such as
+/// `T: PartialOrd<i32>` for `gt(0)`, are not inferred, and should be placed on the struct itself.
+///
+/// Besides `validate`, the derive implements `validate_fields`, which returns the same errors
+/// grouped by the field that produced them in a `vale::FieldErrors`. It also adds an associated
+/// constant `VALIDATED_FIELDS` to the type, which lists the names of the fields that carry
+/// validations, so that a form can show which of its fields are constrained.
+///
+/// Validations run in the order in which they are listed, so in `#[validate(len_lt(10), trim)]` the
+/// length is checked before the value is trimmed. Place `#[validate(transform_first)]` on the
+/// struct or enum to run the transformers of each field before its other validations instead.
+///
+/// The errors are returned in the same order, which is stable: fields in the order in which they
+/// are declared, and the validations of each field in the order in which they are listed, across
+/// all of its attributes. The validations placed on the struct or enum itself come last. This means
+/// that the first error always belongs to the first field that failed.
+///
+/// The validations of a field can be split over several `#[validate]` attributes, as in
+/// `#[validate(gt(0))] #[validate(lt(100))]`, which is the same as `#[validate(gt(0), lt(100))]`.
+/// Attributes of other crates, such as `#[serde(default)]`, can be mixed in freely.
+///
+/// The validations of an attribute can be made conditional by adding `when = "..."` to it, which
+/// contains an expression that usually refers to `self`. For example,
+/// `#[validate(when = "self.needs_shipping", required, nested)]` only validates the field when
+/// `needs_shipping` is `true`.
+///
+/// The arguments of the validations are expressions, so the fields of a struct can use the other
+/// fields in their bounds, for example `#[validate(lt(self.limit))]`. Since the fields are
+/// validated in order, such a field sees the transformed value of a field only if that field is
+/// listed first.
+///
+/// The default error messages contain the name of the field. Use `rename = "..."` to show a
+/// different name instead, for example `#[validate(rename = "User ID", gt(0))]`. The errors
+/// returned by `validate_fields` are still grouped by the name of the field itself.
+///
+/// Fields marked with `#[validate(skip)]` are ignored entirely, including any other attributes they
+/// carry.
+///
+/// Validations that span multiple fields can be placed on the struct or enum itself, using
+/// `#[validate(with(function))]` or `#[validate(try_with(function))]`. The function receives the
+/// entire entity and is run after the fields are validated. A short check can also be written
+/// inline, as in `#[validate(predicate = "self.start < self.end")]`.
+///
+/// The default error messages don't contain the value that failed validation. Place
+/// `#[validate(show_values)]` on the struct or enum to include it, for example "Failed to validate
+/// field `id` = -3, value too low". This requires the validated fields to implement `Debug`.
+///
+/// Place `#[validate(max_errors = 10)]` on the struct or enum to return at most 10 errors from
+/// `validate` and `validate_ref`. Validation stops after the field that reached the cap, so the
+/// remaining fields are not checked, as with `#[vale::ruleset(max_errors = 10)]`.
+///
+/// The generated code refers to this crate as `vale`. If the dependency is renamed, as in
+/// `my_vale = { package = "vale" }`, place `#[validate(crate = ::my_vale)]` on the struct or enum
+/// to provide the path to the crate instead.
+///
+/// With the `tracing` feature, the derived `validate` runs inside a `debug` span called `validate`,
+/// which records the name of the struct or enum as `entity`. At the end of the span, the number of
+/// errors is logged as a `debug` event.
+///
+/// Fields of type `Option<T>` are validated only when they contain a value, and `None` is skipped
+/// silently. Use `required` to reject `None` as well.
+///
+/// Fields of type `Box<T>`, `Rc<T>` and `Arc<T>` are validated through the value they point to, so
+/// `gt(0)` can be used on a `Box<i32>`. Since the value behind an `Rc` or `Arc` can't be modified,
+/// `with`, `try_with` and `nested` can only be used on `Box` fields.
+///
+/// Transformers such as `trim` are applied through the [`vale::Transform`](crate::Transform)
+/// trait, which is implemented for `String`, `Box<str>`, `Rc<str>`, `Arc<str>` and `Cow<str>`, and
+/// for a `Box<T>`, `Rc<T>` or `Arc<T>` whose `T` implements it, in which case the pointer itself is
+/// replaced. A transformer leaves a borrowed `Cow<str>` borrowed when it does not change it, and
+/// replaces it with an owned one when it does. Implement `Transform` to use the transformers on a
+/// type of your own.
 ///
 /// ### Example
 /// ```rust,no_run
@@ -117,6 +567,8 @@ pub use vale_derive::ruleset;
 ///     transformer: String,
 ///     #[validate(len_lt(10), trim)]
 ///     transfailer: String,
+///     #[validate(required, gt(0))]
+///     id: Option<i32>,
 /// }
 ///
 /// fn is_even(num: &mut i32) -> bool {
@@ -125,8 +577,85 @@ pub use vale_derive::ruleset;
 /// ```
 pub use vale_derive::Validate;
 
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "regex")]
+    pub use once_cell::sync::Lazy;
+    #[cfg(feature = "regex")]
+    pub use rgx::Regex;
+    #[cfg(feature = "url")]
+    pub use rl::Url;
+    #[cfg(feature = "tracing")]
+    pub use trc as tracing;
+
+    use alloc::vec::Vec;
+
+    /// The items of the standard prelude that the derived code uses. These are taken from `alloc`,
+    /// so that the derive also works in `no_std` crates.
+    pub mod prelude {
+        pub use alloc::borrow::ToOwned;
+        pub use alloc::boxed::Box;
+        pub use alloc::format;
+        pub use alloc::string::{String, ToString};
+        pub use alloc::vec::Vec;
+    }
+
+    /// Returns the zero of the type of the provided value, which is used by `positive`, `negative`
+    /// and `non_zero`.
+    pub fn zero_of<T: Default>(_: &T) -> T {
+        T::default()
+    }
+
+    /// Returns the number of grapheme clusters in the string, for `count = "graphemes"`.
+    #[cfg(feature = "graphemes")]
+    pub fn grapheme_count(s: &str) -> usize {
+        unicode_segmentation::UnicodeSegmentation::graphemes(s, true).count()
+    }
+
+    /// Checks that no two items of the collection are equal, which is used by `unique_items`. This
+    /// only requires `PartialEq`, so the items are compared pairwise.
+    pub fn all_unique<'a, T: PartialEq + 'a>(items: impl IntoIterator<Item = &'a T>) -> bool {
+        let mut seen: Vec<&T> = Vec::new();
+        for item in items {
+            if seen.contains(&item) {
+                return false;
+            }
+            seen.push(item);
+        }
+        true
+    }
+}
+
 /// A type alias for the `Result` returned by the `Validate::validate` function.
-pub type Result = std::result::Result<(), Vec<String>>;
+pub type Result = core::result::Result<(), Vec<String>>;
+
+/// Combines multiple results into one, which is `Ok` only if all of them are. Otherwise, it
+/// contains the errors of all failed results, in order. This is useful when validating the parts
+/// of an entity by hand.
+///
+/// ### Example
+/// ```rust
+/// let results = vec![
+///     Ok(()),
+///     Err(vec!["too short".to_string()]),
+///     Err(vec!["too long".to_string()]),
+/// ];
+/// assert_eq!(vale::merge(results), Err(vec!["too short".to_string(), "too long".to_string()]));
+/// assert_eq!(vale::merge(vec![Ok(()), Ok(())]), Ok(()));
+/// ```
+pub fn merge(results: impl IntoIterator<Item = Result>) -> Result {
+    let mut errors = Vec::new();
+    for result in results {
+        if let Err(errs) = result {
+            errors.extend(errs);
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
 
 /// The core trait of this library. Any entity that implements `Validate` can be validated by
 /// running the `validate` function. This will either return an `Ok(())`, or an `Err` containing a
@@ -135,5 +664,234 @@ pub type Result = std::result::Result<(), Vec<String>>;
 pub trait Validate {
     /// Performs the validation.
     fn validate(&mut self) -> Result;
+
+    /// Performs the validation, and groups the errors by the field that produced them.
+    /// `#[derive(Validate)]` implements this for you. The default implementation can't tell which
+    /// field an error belongs to, so it stores all errors under the empty key `""`.
+    fn validate_fields(&mut self) -> core::result::Result<(), FieldErrors> {
+        self.validate().map_err(|errors| {
+            let mut field_errors = FieldErrors::new();
+            field_errors.extend("", errors);
+            field_errors
+        })
+    }
+
+    /// Performs the validation of a value that is nested in another, as with `#[validate(nested)]`,
+    /// and groups the errors by their dotted path, which starts with `prefix`. For example, with
+    /// the prefix `customer`, the errors of the field `email` are stored under `customer.email`,
+    /// and those of the value itself under `customer`. The default implementation builds on
+    /// `validate_fields`, which includes the paths of deeper nested values, such as
+    /// `address.street`.
+    fn validate_nested(&mut self, prefix: &str) -> core::result::Result<(), FieldErrors> {
+        self.validate_fields().map_err(|errors| {
+            let mut nested = FieldErrors::new();
+            for (field, errors) in errors.iter() {
+                let path = match field {
+                    "" => prefix.into(),
+                    field => format!("{}.{}", prefix, field),
+                };
+                nested.extend(&path, errors.iter().cloned());
+            }
+            nested
+        })
+    }
+
+    /// Performs the validation, and returns the advisory warnings besides the result. Warnings do
+    /// not make the validation fail, and are recorded with `vale::rule_warn`. The default
+    /// implementation returns no warnings. See `vale::rule_warn` for how to implement this together
+    /// with `validate`.
+    fn validate_with_warnings(&mut self) -> (Result, Vec<String>) {
+        (self.validate(), Vec::new())
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate(&mut self) -> Result {
+        (**self).validate()
+    }
+
+    fn validate_fields(&mut self) -> core::result::Result<(), FieldErrors> {
+        (**self).validate_fields()
+    }
+
+    fn validate_with_warnings(&mut self) -> (Result, Vec<String>) {
+        (**self).validate_with_warnings()
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for &mut T {
+    fn validate(&mut self) -> Result {
+        (**self).validate()
+    }
+
+    fn validate_fields(&mut self) -> core::result::Result<(), FieldErrors> {
+        (**self).validate_fields()
+    }
+
+    fn validate_with_warnings(&mut self) -> (Result, Vec<String>) {
+        (**self).validate_with_warnings()
+    }
+}
+
+/// Validates the contained value, if there is one. `None` is always valid.
+impl<T: Validate> Validate for Option<T> {
+    fn validate(&mut self) -> Result {
+        match self {
+            Some(inner) => inner.validate(),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_fields(&mut self) -> core::result::Result<(), FieldErrors> {
+        match self {
+            Some(inner) => inner.validate_fields(),
+            None => Ok(()),
+        }
+    }
+
+    fn validate_with_warnings(&mut self) -> (Result, Vec<String>) {
+        match self {
+            Some(inner) => inner.validate_with_warnings(),
+            None => (Ok(()), Vec::new()),
+        }
+    }
+}
+
+/// Validates every element of the `Vec`. The errors of each element are prefixed with its index,
+/// for example `[2]: ...`.
+impl<T: Validate> Validate for Vec<T> {
+    fn validate(&mut self) -> Result {
+        validate_all(self)
+    }
+}
+
+/// Validates every item of the slice, and returns the errors of all of them. The errors of each
+/// item are prefixed with its index, for example `[2]: ...`, in the same way as those of a `Vec`.
+///
+/// ### Example
+/// ```rust
+/// #[derive(vale::Validate)]
+/// struct Item {
+///     #[validate(gt(0))]
+///     amount: i32,
+/// }
+///
+/// let mut items = [Item { amount: 1 }, Item { amount: 0 }];
+/// assert_eq!(
+///     vale::validate_all(&mut items),
+///     Err(vec!["[1]: Failed to validate field `amount`, value too low".to_string()]),
+/// );
+/// ```
+pub fn validate_all<T: Validate>(items: &mut [T]) -> Result {
+    let mut errors = Vec::new();
+    for (idx, item) in items.iter_mut().enumerate() {
+        if let Err(errs) = item.validate() {
+            errors.extend(errs.into_iter().map(|e| format!("[{}]: {}", idx, e)));
+        }
+    }
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+/// Validates the provided value and evaluates to it if it is valid. Otherwise, the errors are
+/// returned from the surrounding function, which should therefore return a `Result` whose error
+/// type implements `From<Vec<String>>`, such as `Vec<String>` itself. A function can be passed as
+/// the second argument to map the errors to another error type first. The value is taken by value,
+/// so pass `&mut value` to validate something you do not want to move.
+///
+/// ### Example
+/// ```rust
+/// #[derive(vale::Validate)]
+/// struct Signup {
+///     #[validate(trim, len_gt(2))]
+///     name: String,
+/// }
+///
+/// fn signup(signup: Signup) -> Result<String, Vec<String>> {
+///     let signup = vale::validated!(signup);
+///     Ok(format!("Welcome, {}", signup.name))
+/// }
+///
+/// fn signup_status(signup: Signup) -> Result<String, u16> {
+///     let signup = vale::validated!(signup, |_| 422);
+///     Ok(signup.name)
+/// }
+///
+/// assert_eq!(signup(Signup { name: " luuk ".to_string() }), Ok("Welcome, luuk".to_string()));
+/// assert_eq!(signup_status(Signup { name: "lu".to_string() }), Err(422));
+/// ```
+#[macro_export]
+macro_rules! validated {
+    ($value:expr $(,)?) => {
+        $crate::validated!($value, ::core::convert::From::from)
+    };
+    ($value:expr, $map:expr $(,)?) => {
+        match $value {
+            mut value => match $crate::Validate::validate(&mut value) {
+                ::core::result::Result::Ok(()) => value,
+                ::core::result::Result::Err(errors) => {
+                    return ::core::result::Result::Err(($map)(errors))
+                }
+            },
+        }
+    };
+}
+
+/// Validation of an entity that needs some context, for example a list of allowed values that is
+/// only known at runtime. The `ruleset` macro can be used to implement this trait, in the same way
+/// as it is used for `Validate`.
+///
+/// ### Example
+/// ```rust
+/// struct Config {
+///     allowed_hosts: Vec<String>,
+/// }
+///
+/// struct Request {
+///     host: String,
+/// }
+///
+/// impl vale::ValidateWith<Config> for Request {
+///     #[vale::ruleset]
+///     fn validate_with(&mut self, config: &Config) -> vale::Result {
+///         let allowed = config.allowed_hosts.contains(&self.host);
+///         vale::rule!(allowed, "host `{}` not allowed", self.host);
+///     }
+/// }
+/// ```
+pub trait ValidateWith<C: ?Sized> {
+    /// Performs the validation, using the provided context.
+    fn validate_with(&mut self, ctx: &C) -> Result;
+}
+
+/// Validation of an entity through a shared reference, for when the entity can't be borrowed
+/// mutably, for example because it is cached. Since transformations can't be performed this way,
+/// `#[derive(Validate)]` only implements this trait when none of the fields use a transformer, or
+/// the `with`, `try_with` or `nested` validations.
+///
+/// ### Example
+/// ```rust
+/// use vale::ValidateRef;
+///
+/// #[derive(vale::Validate)]
+/// struct Entity {
+///     #[validate(gt(0))]
+///     id: i32,
+/// }
+///
+/// let entity = Entity { id: 1 };
+/// assert!(entity.validate_ref().is_ok());
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be validated by reference",
+    note = "`#[derive(Validate)]` does not implement `ValidateRef` when a field uses a \
+            transformer, or the `with`, `try_with` or `nested` validations"
+)]
+pub trait ValidateRef {
+    /// Performs the validation without modifying the entity.
+    fn validate_ref(&self) -> Result;
 }
 
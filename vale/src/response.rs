@@ -0,0 +1,136 @@
+use crate::{FieldErrors, Validate};
+
+/// The format of the body that the web framework integrations send when a request body fails
+/// validation. The default is `JsonArray`.
+///
+/// How the format is configured depends on the framework:
+/// - `actix-web`: register it as app data, using `App::app_data(vale::ResponseFormat::Text)`.
+/// - `axum`: add it to the request extensions, for example with the layer
+///   `axum::Extension(vale::ResponseFormat::Text)`.
+/// - `warp`: pass it to `vale::warp::validated_json_with`.
+/// - `tower`: add it to the request extensions before the request reaches
+///   `vale::tower::ValidateLayer`.
+/// - `rocket`: attach it as a fairing, using `Rocket::attach(vale::ResponseFormat::Text)`, and send
+///   the `vale::validation_error_body` of the request from a catcher.
+///
+/// ### Example
+/// ```rust
+/// struct User {
+///     name: String,
+/// }
+///
+/// impl vale::Validate for User {
+///     fn validate(&mut self) -> vale::Result {
+///         vale::Validator::new().rule(!self.name.is_empty(), "name is empty").finish()
+///     }
+/// }
+///
+/// let body = vale::ResponseFormat::Text
+///     .validate(&mut User { name: String::new() })
+///     .unwrap_err();
+/// assert_eq!(body.content_type(), "text/plain; charset=utf-8");
+/// assert_eq!(body.body(), "name is empty");
+/// ```
+///
+/// ### Features
+/// Requires one of the `rocket`, `actix`, `axum`, `warp` or `tower` features to be enabled
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ResponseFormat {
+    /// A JSON array of the errors, for example `["msg1", "msg2"]`.
+    #[default]
+    JsonArray,
+    /// A JSON object that maps each field to its errors, for example `{"name": ["msg1", "msg2"]}`.
+    /// The errors are collected using `Validate::validate_fields`.
+    JsonObject,
+    /// Plain text, with one error per line.
+    Text,
+}
+
+impl ResponseFormat {
+    /// Validates the provided value, and returns the body that should be sent if it failed. For
+    /// `JsonObject`, this uses `Validate::validate_fields`, and `Validate::validate` otherwise.
+    pub fn validate<T: Validate + ?Sized>(self, value: &mut T) -> Result<(), ErrorBody> {
+        let (errors, fields) = match self {
+            Self::JsonObject => match value.validate_fields() {
+                Ok(()) => return Ok(()),
+                Err(fields) => (fields.clone().into(), Some(fields)),
+            },
+            Self::JsonArray | Self::Text => match value.validate() {
+                Ok(()) => return Ok(()),
+                Err(errors) => (errors, None),
+            },
+        };
+        Err(ErrorBody { format: self, errors, fields })
+    }
+
+    /// Validates an element of a list, like `validate`, but prefixes the errors with the index of
+    /// the element, as in `[2]: ...`. For `JsonObject`, the errors are grouped by their path, such
+    /// as `[2].name`.
+    #[cfg(feature = "rocket")]
+    pub(crate) fn validate_element<T: Validate + ?Sized>(
+        self,
+        value: &mut T,
+        index: usize,
+    ) -> Result<(), ErrorBody> {
+        let prefix = format!("[{}]", index);
+        let (errors, fields) = match self {
+            Self::JsonObject => match value.validate_nested(&prefix) {
+                Ok(()) => return Ok(()),
+                Err(nested) => {
+                    let mut fields = FieldErrors::new();
+                    for (path, errors) in nested.iter() {
+                        fields.extend(path, errors.iter().map(|e| format!("{}: {}", prefix, e)));
+                    }
+                    (fields.clone().into(), Some(fields))
+                }
+            },
+            Self::JsonArray | Self::Text => match value.validate() {
+                Ok(()) => return Ok(()),
+                Err(errors) => (errors.into_iter().map(|e| format!("{}: {}", prefix, e)).collect(), None),
+            },
+        };
+        Err(ErrorBody { format: self, errors, fields })
+    }
+}
+
+/// The errors of a failed validation, together with the `ResponseFormat` in which they should be
+/// sent. This is produced by `ResponseFormat::validate`.
+///
+/// ### Features
+/// Requires one of the `rocket`, `actix`, `axum`, `warp` or `tower` features to be enabled
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ErrorBody {
+    format: ResponseFormat,
+    pub(crate) errors: Vec<String>,
+    fields: Option<FieldErrors>,
+}
+
+impl ErrorBody {
+    /// Returns the format in which the errors are sent.
+    pub fn format(&self) -> ResponseFormat {
+        self.format
+    }
+
+    /// Returns the errors as a single list. For `JsonObject`, these are in the order of the fields.
+    pub fn errors(&self) -> &[String] {
+        &self.errors
+    }
+
+    /// Returns the value of the `Content-Type` header of the response.
+    pub fn content_type(&self) -> &'static str {
+        match self.format {
+            ResponseFormat::JsonArray | ResponseFormat::JsonObject => "application/json",
+            ResponseFormat::Text => "text/plain; charset=utf-8",
+        }
+    }
+
+    /// Renders the errors in the format of this body.
+    pub fn body(&self) -> String {
+        let json = match (self.format, &self.fields) {
+            (ResponseFormat::Text, _) => return self.errors.join("\n"),
+            (ResponseFormat::JsonObject, Some(fields)) => serde_json::to_string(fields),
+            _ => serde_json::to_string(&self.errors),
+        };
+        json.expect("a list of strings can always be serialized")
+    }
+}
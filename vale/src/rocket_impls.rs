@@ -1,6 +1,13 @@
-use rkt::data::{Data, FromData, Outcome, Transform, Transformed};
-use rkt::http::Status;
-use rkt::request::Request;
+use crate::{ErrorBody, ResponseFormat};
+use rkt::data::{Data, FromData, FromDataSimple, Outcome, Transform, Transformed};
+use rkt::fairing::{Fairing, Info, Kind};
+use rkt::http::{ContentType, Status};
+use rkt::request::{Form, LenientForm, Request};
+use rkt::response::{self, Responder, Response};
+use serde::de::{self, DeserializeOwned, SeqAccess, Visitor};
+use std::fmt;
+use std::io::{Cursor, Read};
+use std::marker::PhantomData;
 use std::ops::Deref;
 use std::ops::DerefMut;
 
@@ -15,7 +22,10 @@ use std::ops::DerefMut;
 /// # struct User {}
 /// # impl rocket::data::FromDataSimple for User { 
 /// #     type Error = String;
-/// #     fn from_data(req: &rocket::Request, data: rocket::Data) -> rocket::data::Outcome<Self, String> {
+/// #     fn from_data(
+/// #         req: &rocket::Request,
+/// #         data: rocket::Data,
+/// #     ) -> rocket::data::Outcome<Self, String> {
 /// #         rocket::data::Outcome::Success(Self {})
 /// #     }
 /// # }
@@ -30,7 +40,11 @@ use std::ops::DerefMut;
 /// # fn main() {}
 /// ```
 ///
-/// It is also possible to nest this type with other wrappers:
+/// It is also possible to nest this type with other wrappers that implement `Validate`. This crate
+/// implements `Validate` for `rocket::request::Form<T>`, `rocket::request::LenientForm<T>`,
+/// `Box<T>`, `Option<T>` and `Vec<T>` when `T` implements it, and for
+/// `rocket_contrib::json::Json<T>` when the `rocket_contrib` feature is enabled, which it is by
+/// default. A route can therefore accept a validated JSON body, or even a list of them:
 ///
 /// ```rust
 /// # #![feature(decl_macro, proc_macro_hygiene)]
@@ -43,8 +57,23 @@ use std::ops::DerefMut;
 /// fn update_user(user: Valid<Json<User>>) {
 ///     let user = user.into_inner().into_inner();
 /// }
+///
+/// #[rocket::post("/users", data = "<users>")]
+/// fn update_users(users: Valid<Json<Vec<User>>>) {
+///     let users = users.into_inner().into_inner();
+/// }
 /// # fn main() {}
 /// ```
+///
+/// Forms work the same way, so a route can accept a `Valid<Form<T>>` or a `Valid<LenientForm<T>>`
+/// for any `T` that implements both `FromForm` and `Validate`. The form is validated after it is
+/// parsed, and a failed validation is handled like that of any other body.
+///
+/// When the body can't be parsed, the request fails with the status of the inner type, such as
+/// `400 Bad Request` for a malformed JSON body. When the body is parsed but fails validation, the
+/// request fails with `400 Bad Request` as well, unless the `unprocessable_entity` feature is
+/// enabled, in which case `422 Unprocessable Entity` is used to tell the two apart.
+///
 /// ### Features
 /// Requires the `rocket` feature to be enabled
 pub struct Valid<T> {
@@ -64,6 +93,60 @@ impl<T: crate::Validate> Valid<T> {
     }
 }
 
+/// Returns the errors of the validation that failed while handling this request, if any. This can
+/// be used from a catcher to show the user why their request was rejected. With the
+/// `unprocessable_entity` feature enabled, the catcher should be registered for `422` instead.
+///
+/// Only the errors of the first validation that failed are kept. Normally a failed validation
+/// ends the request, but when a route catches the failure of one guard, for example by taking an
+/// `Option<Valid<T>>`, the errors of a later guard are not recorded.
+///
+/// ### Example
+/// ```rust
+/// # #![feature(decl_macro, proc_macro_hygiene)]
+/// # extern crate rkt as rocket;
+/// #[rocket::catch(400)]
+/// fn bad_request(req: &rocket::Request) -> String {
+///     match vale::validation_errors(req) {
+///         Some(errors) => errors.join("\n"),
+///         None => "Bad request".to_string(),
+///     }
+/// }
+///
+/// # fn main() {
+/// rocket::ignite().register(rocket::catchers![bad_request]);
+/// # }
+/// ```
+pub fn validation_errors<'r>(req: &'r Request) -> Option<&'r [String]> {
+    validation_error_body(req).map(ErrorBody::errors)
+}
+
+/// Returns the errors of the validation that failed while handling this request, in the
+/// `ResponseFormat` that was attached as a fairing. If no format was attached, the errors are
+/// formatted as a JSON array. The returned body can be sent from a catcher. Like
+/// `validation_errors`, only the first failed validation of a request is kept.
+///
+/// ### Example
+/// ```rust
+/// # #![feature(decl_macro, proc_macro_hygiene)]
+/// # extern crate rkt as rocket;
+/// use vale::{ErrorBody, ResponseFormat};
+///
+/// #[rocket::catch(400)]
+/// fn bad_request(req: &rocket::Request) -> Option<ErrorBody> {
+///     vale::validation_error_body(req).cloned()
+/// }
+///
+/// # fn main() {
+/// rocket::ignite()
+///     .attach(ResponseFormat::JsonObject)
+///     .register(rocket::catchers![bad_request]);
+/// # }
+/// ```
+pub fn validation_error_body<'r>(req: &'r Request) -> Option<&'r ErrorBody> {
+    req.local_cache(|| ValidationErrors(None)).0.as_ref()
+}
+
 impl<T: crate::Validate> Deref for Valid<T> {
     type Target = T;
 
@@ -78,8 +161,167 @@ impl<T: crate::Validate> DerefMut for Valid<T> {
     }
 }
 
+/// A validated JSON array, whose elements are validated while the request body is parsed. Unlike
+/// `Valid<Json<Vec<T>>>`, this does not read the entire body into memory before parsing it, and it
+/// stops reading at the first element that fails validation. The errors of that element are
+/// prefixed with its index, for example `[2]: ...`, and the remaining elements are not validated.
+///
+/// The size of the body is limited by the `json` limit of Rocket, like it is for
+/// `rocket_contrib::json::Json`, which is 1 MiB by default. Raise it in `Rocket.toml` to accept
+/// larger arrays.
+///
+/// ### Example
+/// ```rust
+/// # #![feature(decl_macro, proc_macro_hygiene)]
+/// # #[derive(vale::Validate, serde::Deserialize)]
+/// # struct Measurement {}
+/// # extern crate rkt as rocket;
+/// use vale::ValidStream;
+///
+/// #[rocket::post("/measurements", data = "<measurements>")]
+/// fn upload(measurements: ValidStream<Measurement>) -> String {
+///     format!("stored {} measurements", measurements.len())
+/// }
+/// # fn main() {}
+/// ```
+///
+/// ### Features
+/// Requires the `rocket` feature to be enabled
+pub struct ValidStream<T> {
+    items: Vec<T>,
+}
+
+impl<T> ValidStream<T> {
+    /// Consumes the `ValidStream` wrapper and returns the validated elements.
+    pub fn into_inner(self) -> Vec<T> {
+        self.items
+    }
+}
+
+impl<T> Deref for ValidStream<T> {
+    type Target = Vec<T>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.items
+    }
+}
+
+impl<T> DerefMut for ValidStream<T> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.items
+    }
+}
+
+/// The size limit of a streamed body if Rocket has no `json` limit configured, which is the same as
+/// that of `rocket_contrib::json::Json`.
+const STREAM_LIMIT: u64 = 1 << 20;
+
+/// Deserializes the elements of a JSON array one by one, and validates each of them before the next
+/// is read. The errors of the first invalid element are stored in `failure`.
+struct StreamVisitor<'a, T> {
+    format: ResponseFormat,
+    failure: &'a mut Option<ErrorBody>,
+    marker: PhantomData<T>,
+}
+
+impl<'de, T> Visitor<'de> for StreamVisitor<'_, T>
+where
+    T: DeserializeOwned + crate::Validate
+{
+    type Value = Vec<T>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("an array")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut items = Vec::new();
+        while let Some(mut item) = seq.next_element::<T>()? {
+            if let Err(body) = self.format.validate_element(&mut item, items.len()) {
+                *self.failure = Some(body);
+                return Err(de::Error::custom("element failed validation"));
+            }
+            items.push(item);
+        }
+        Ok(items)
+    }
+}
+
+impl<T> FromDataSimple for ValidStream<T>
+where
+    T: DeserializeOwned + crate::Validate
+{
+    type Error = ValidationError<serde_json::Error>;
+
+    fn from_data(r: &Request, d: Data) -> Outcome<Self, Self::Error> {
+        let limit = r.limits().get("json").unwrap_or(STREAM_LIMIT);
+        let format = *r.local_cache(ResponseFormat::default);
+        let mut failure = None;
+        let visitor = StreamVisitor { format, failure: &mut failure, marker: PhantomData };
+        let mut deserializer = serde_json::Deserializer::from_reader(d.open().take(limit));
+        let result = de::Deserializer::deserialize_seq(&mut deserializer, visitor)
+            .and_then(|items| deserializer.end().map(|_| items));
+        if let Some(body) = failure {
+            let msg = body.errors().to_vec();
+            r.local_cache(|| ValidationErrors(Some(body)));
+            return Outcome::Failure((VALIDATION_STATUS, msg.into()));
+        }
+        match result {
+            Ok(items) => Outcome::Success(ValidStream { items }),
+            Err(e) => Outcome::Failure((Status::BadRequest, ValidationError::from_data_error(e))),
+        }
+    }
+}
+
+/// The status of a request whose body was parsed, but failed validation.
+#[cfg(not(feature = "unprocessable_entity"))]
+const VALIDATION_STATUS: Status = Status::BadRequest;
+#[cfg(feature = "unprocessable_entity")]
+const VALIDATION_STATUS: Status = Status::UnprocessableEntity;
+
+/// The errors of a failed validation, which are stored in the request-local cache.
+struct ValidationErrors(Option<ErrorBody>);
+
+/// Attaching a `ResponseFormat` as a fairing sets the format of the `validation_error_body` of
+/// every request.
+impl Fairing for ResponseFormat {
+    fn info(&self) -> Info {
+        Info {
+            name: "vale response format",
+            kind: Kind::Request,
+        }
+    }
+
+    fn on_request(&self, request: &mut Request, _: &Data) {
+        request.local_cache(|| *self);
+    }
+}
+
+/// Responds with the rendered errors and the matching `Content-Type`. The status is left to the
+/// catcher that sends it.
+impl<'r> Responder<'r> for ErrorBody {
+    fn respond_to(self, _: &Request) -> response::Result<'r> {
+        let content_type = match self.format() {
+            ResponseFormat::JsonArray | ResponseFormat::JsonObject => ContentType::JSON,
+            ResponseFormat::Text => ContentType::Plain,
+        };
+        Response::build()
+            .header(content_type)
+            .sized_body(Cursor::new(self.body()))
+            .ok()
+    }
+}
+
+/// The error of a `Valid<T>` that could not be created from the request body. Either the inner type
+/// `T` failed to parse the body, or the parsed value failed validation.
+///
+/// ### Features
+/// Requires the `rocket` feature to be enabled
+#[derive(Debug)]
 pub enum ValidationError<T> {
+    /// The error of the inner type, for example a malformed JSON body.
     FromDataError(T),
+    /// The errors of the failed validation.
     ValidationError(Vec<String>),
 }
 
@@ -94,14 +336,26 @@ impl<T> ValidationError<T> {
         Self::FromDataError(t)
     }
 
-    fn get_t(self) -> T {
+    /// Returns the error of the inner type, or `None` if the body failed validation.
+    pub fn into_from_data_error(self) -> Option<T> {
+        match self {
+            Self::FromDataError(t) => Some(t),
+            Self::ValidationError(_) => None,
+        }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for ValidationError<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match self {
-            Self::FromDataError(t) => t,
-            Self::ValidationError(_) => panic!("Called `get_t` on `ValidationError::FromDataError`"),
+            Self::FromDataError(t) => write!(f, "failed to read the request body: {}", t),
+            Self::ValidationError(errors) => write!(f, "validation failed: {}", errors.join(", ")),
         }
     }
 }
 
+impl<T: fmt::Debug + fmt::Display> std::error::Error for ValidationError<T> {}
+
 impl<'a, T: 'a> FromData<'a> for Valid<T>
 where
     T: FromData<'a> + crate::Validate
@@ -120,33 +374,70 @@ where
     }
 
     fn from_data(r: &Request, o: Transformed<'a, Self>) -> Outcome<Self, Self::Error> {
+        // a failed `transform` is returned as-is, so the outcome passed to `T` never contains one
         let outcome = match o {
-            Transform::Owned(o) => {
-                Transform::Owned(o.map_failure(|(s, f)| (s, f.get_t())))
-            }
-            Transform::Borrowed(o) => {
-                Transform::Borrowed(o.map_failure(|(s, f)| (s, f.get_t())))
+            Transform::Owned(Outcome::Failure(f)) | Transform::Borrowed(Outcome::Failure(f)) => {
+                return Outcome::Failure(f);
             }
+            Transform::Owned(Outcome::Success(s)) => Transform::Owned(Outcome::Success(s)),
+            Transform::Owned(Outcome::Forward(f)) => Transform::Owned(Outcome::Forward(f)),
+            Transform::Borrowed(Outcome::Success(s)) => Transform::Borrowed(Outcome::Success(s)),
+            Transform::Borrowed(Outcome::Forward(f)) => Transform::Borrowed(Outcome::Forward(f)),
         };
         let mut inner = match T::from_data(r, outcome) {
             Outcome::Success(s) => s,
             Outcome::Failure((s, f)) => return Outcome::Failure((s, Self::Error::from_data_error(f))),
             Outcome::Forward(f) => return Outcome::Forward(f),
         };
-        if let Err(msg) = inner.validate() {
-            return Outcome::Failure((Status::BadRequest, msg.into()));
+        let format = *r.local_cache(ResponseFormat::default);
+        if let Err(body) = format.validate(&mut inner) {
+            let msg = body.errors().to_vec();
+            r.local_cache(|| ValidationErrors(Some(body)));
+            return Outcome::Failure((VALIDATION_STATUS, msg.into()));
         }
         Outcome::Success(Valid::new(inner))
     }
 }
 
-impl<T, U> crate::Validate for U
-where
-    U: Deref<Target=T> + DerefMut,
-    T: crate::Validate,
-{
+impl<T: crate::Validate> crate::Validate for Form<T> {
+    fn validate(&mut self) -> crate::Result {
+        self.0.validate()
+    }
+
+    fn validate_fields(&mut self) -> Result<(), crate::FieldErrors> {
+        self.0.validate_fields()
+    }
+
+    fn validate_with_warnings(&mut self) -> (crate::Result, Vec<String>) {
+        self.0.validate_with_warnings()
+    }
+}
+
+impl<T: crate::Validate> crate::Validate for LenientForm<T> {
+    fn validate(&mut self) -> crate::Result {
+        self.0.validate()
+    }
+
+    fn validate_fields(&mut self) -> Result<(), crate::FieldErrors> {
+        self.0.validate_fields()
+    }
+
+    fn validate_with_warnings(&mut self) -> (crate::Result, Vec<String>) {
+        self.0.validate_with_warnings()
+    }
+}
+
+#[cfg(feature = "rocket_contrib")]
+impl<T: crate::Validate> crate::Validate for rkt_contrib::json::Json<T> {
     fn validate(&mut self) -> Result<(), Vec<String>> {
-        let t: &mut T = self.deref_mut();
-        t.validate()
+        self.0.validate()
+    }
+
+    fn validate_fields(&mut self) -> Result<(), crate::FieldErrors> {
+        self.0.validate_fields()
+    }
+
+    fn validate_with_warnings(&mut self) -> (crate::Result, Vec<String>) {
+        self.0.validate_with_warnings()
     }
 }
@@ -0,0 +1,87 @@
+use chr::{DateTime, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use core::cmp::Ordering;
+
+/// A point in time, or a date, that can be compared against the current time. This is implemented
+/// for the `DateTime`, `NaiveDateTime` and `NaiveDate` types of `chrono`. Naive values are assumed
+/// to be in UTC, so a `NaiveDate` is compared against the current date in UTC.
+///
+/// ### Features
+/// Requires the `chrono` feature to be enabled
+pub trait Moment {
+    /// Compares this moment to the instant `now`.
+    fn cmp_to(&self, now: &DateTime<Utc>) -> Ordering;
+}
+
+impl<Tz: TimeZone> Moment for DateTime<Tz> {
+    fn cmp_to(&self, now: &DateTime<Utc>) -> Ordering {
+        self.with_timezone(&Utc).cmp(now)
+    }
+}
+
+impl Moment for NaiveDateTime {
+    fn cmp_to(&self, now: &DateTime<Utc>) -> Ordering {
+        self.cmp(&now.naive_utc())
+    }
+}
+
+impl Moment for NaiveDate {
+    fn cmp_to(&self, now: &DateTime<Utc>) -> Ordering {
+        self.cmp(&now.date_naive())
+    }
+}
+
+/// Checks whether the provided moment lies after the current time. For dates, today does not count
+/// as the future.
+///
+/// ### Features
+/// Requires the `chrono` feature to be enabled
+pub fn is_future<T: Moment + ?Sized>(moment: &T) -> bool {
+    is_future_at(moment, &Utc::now())
+}
+
+/// Checks whether the provided moment lies after `now`. This is what `vale::is_future` uses, with
+/// the current time filled in.
+///
+/// ### Example
+/// ```rust
+/// # extern crate chr as chrono;
+/// use chrono::{NaiveDate, TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2020, 6, 1, 12, 0, 0).unwrap();
+/// assert!(vale::is_future_at(&NaiveDate::from_ymd_opt(2020, 6, 2).unwrap(), &now));
+/// assert!(!vale::is_future_at(&NaiveDate::from_ymd_opt(2020, 6, 1).unwrap(), &now));
+/// ```
+///
+/// ### Features
+/// Requires the `chrono` feature to be enabled
+pub fn is_future_at<T: Moment + ?Sized>(moment: &T, now: &DateTime<Utc>) -> bool {
+    moment.cmp_to(now) == Ordering::Greater
+}
+
+/// Checks whether the provided moment lies before the current time. For dates, today does not count
+/// as the past.
+///
+/// ### Features
+/// Requires the `chrono` feature to be enabled
+pub fn is_past<T: Moment + ?Sized>(moment: &T) -> bool {
+    is_past_at(moment, &Utc::now())
+}
+
+/// Checks whether the provided moment lies before `now`. This is what `vale::is_past` uses, with
+/// the current time filled in.
+///
+/// ### Example
+/// ```rust
+/// # extern crate chr as chrono;
+/// use chrono::{TimeZone, Utc};
+///
+/// let now = Utc.with_ymd_and_hms(2020, 6, 1, 12, 0, 0).unwrap();
+/// assert!(vale::is_past_at(&Utc.with_ymd_and_hms(2020, 6, 1, 11, 59, 59).unwrap(), &now));
+/// assert!(!vale::is_past_at(&now, &now));
+/// ```
+///
+/// ### Features
+/// Requires the `chrono` feature to be enabled
+pub fn is_past_at<T: Moment + ?Sized>(moment: &T, now: &DateTime<Utc>) -> bool {
+    moment.cmp_to(now) == Ordering::Less
+}
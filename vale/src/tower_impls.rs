@@ -0,0 +1,130 @@
+use crate::{ErrorBody, ResponseFormat};
+use std::future::Future;
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use tower_layer::Layer;
+use tower_service::Service;
+
+/// A `tower` layer that validates the body of every request before it reaches the inner service.
+/// The body is expected to be deserialized already, so the layer wraps services that accept an
+/// `http::Request<T>` where `T` implements `Validate`. If the validation fails, the inner service
+/// is not called, and a `400 Bad Request` with the validation errors is returned instead, like the
+/// other integrations do. By default, the body is a JSON array of the errors. This can be changed by
+/// adding a `vale::ResponseFormat` to the request extensions.
+///
+/// ### Example
+/// ```rust
+/// # #[derive(vale::Validate)]
+/// # struct User {}
+/// use std::convert::Infallible;
+/// use tower_layer::Layer;
+///
+/// async fn update_user(req: http::Request<User>) -> Result<http::Response<String>, Infallible> {
+///     // the body is now validated, this code is not reached if the validation failed
+///     Ok(http::Response::new(String::new()))
+/// }
+///
+/// let service = vale::tower::ValidateLayer::new().layer(tower::service_fn(update_user));
+/// ```
+///
+/// ### Features
+/// Requires the `tower` feature to be enabled
+#[derive(Debug, Default, Clone, Copy)]
+pub struct ValidateLayer;
+
+impl ValidateLayer {
+    /// Creates a new layer.
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+impl<S> Layer<S> for ValidateLayer {
+    type Service = ValidateService<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ValidateService { inner }
+    }
+}
+
+/// The service that is created by `ValidateLayer`.
+///
+/// ### Features
+/// Requires the `tower` feature to be enabled
+#[derive(Debug, Clone)]
+pub struct ValidateService<S> {
+    inner: S,
+}
+
+impl<S> ValidateService<S> {
+    /// Consumes the `ValidateService` and returns the inner service.
+    pub fn into_inner(self) -> S {
+        self.inner
+    }
+}
+
+impl<S, T, B> Service<http::Request<T>> for ValidateService<S>
+where
+    S: Service<http::Request<T>, Response = http::Response<B>>,
+    T: crate::Validate,
+    B: From<String>,
+{
+    type Response = http::Response<B>;
+    type Error = S::Error;
+    type Future = ResponseFuture<S::Future, B>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut req: http::Request<T>) -> Self::Future {
+        let format = req.extensions().get::<ResponseFormat>().copied().unwrap_or_default();
+        let kind = match format.validate(req.body_mut()) {
+            Ok(()) => Kind::Inner(Box::pin(self.inner.call(req))),
+            Err(body) => Kind::Rejected(Some(rejection(&body))),
+        };
+        ResponseFuture { kind }
+    }
+}
+
+/// Creates the `400 Bad Request` response for the provided validation errors.
+fn rejection<B: From<String>>(body: &ErrorBody) -> http::Response<B> {
+    let mut resp = http::Response::new(B::from(body.body()));
+    *resp.status_mut() = http::StatusCode::BAD_REQUEST;
+    resp.headers_mut().insert(
+        http::header::CONTENT_TYPE,
+        http::HeaderValue::from_static(body.content_type()),
+    );
+    resp
+}
+
+/// The future that is returned by `ValidateService`. It resolves to the response of the inner
+/// service, or to the rejection if the validation failed.
+///
+/// ### Features
+/// Requires the `tower` feature to be enabled
+pub struct ResponseFuture<F, B> {
+    kind: Kind<F, B>,
+}
+
+enum Kind<F, B> {
+    Inner(Pin<Box<F>>),
+    Rejected(Option<http::Response<B>>),
+}
+
+// the rejection is never pinned, and the inner future is pinned through its `Box`
+impl<F, B> Unpin for ResponseFuture<F, B> {}
+
+impl<F, B, E> Future for ResponseFuture<F, B>
+where
+    F: Future<Output = Result<http::Response<B>, E>>,
+{
+    type Output = Result<http::Response<B>, E>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        match &mut self.get_mut().kind {
+            Kind::Inner(inner) => inner.as_mut().poll(cx),
+            Kind::Rejected(resp) => Poll::Ready(Ok(resp.take().expect("polled after completion"))),
+        }
+    }
+}
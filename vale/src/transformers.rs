@@ -0,0 +1,229 @@
+use alloc::borrow::{Cow, ToOwned};
+use alloc::boxed::Box;
+use alloc::rc::Rc;
+use alloc::string::String;
+use alloc::sync::Arc;
+
+/// A string type that the transformers of `#[derive(Validate)]`, such as `trim` and
+/// `to_lower_case`, can be applied to. The derive calls the method of the same name, so using a
+/// transformer on a field of another type reports that `Transform` is not implemented for it.
+///
+/// This is implemented for `String`, `Box<str>`, `Rc<str>`, `Arc<str>` and `Cow<str>`, and for a
+/// `Box`, `Rc` or `Arc` that contains a type that implements it, in which case the pointer itself
+/// is replaced. To support another type, implement `as_str` and `from_string`. The transformers
+/// are implemented in terms of those two, and only replace the value when they change it, so a
+/// value that is already in shape is left as it is, without allocating.
+///
+/// Note that `trim` and `truncate` share their names with methods of `String` and `str`, so call
+/// them as `vale::Transform::trim(&mut value)` rather than importing this trait.
+///
+/// ### Example
+/// ```rust
+/// struct Name(String);
+///
+/// impl vale::Transform for Name {
+///     fn as_str(&self) -> &str {
+///         &self.0
+///     }
+///
+///     fn from_string(s: String) -> Self {
+///         Name(s)
+///     }
+/// }
+///
+/// let mut name = Name(" john ".to_string());
+/// vale::Transform::trim(&mut name);
+/// vale::Transform::capitalize(&mut name);
+/// assert_eq!(name.0, "John");
+/// ```
+#[diagnostic::on_unimplemented(
+    message = "Transform not implemented for `{Self}`",
+    note = "transformers such as `trim` and `to_lower_case` can only be used on string types, \
+            such as `String` and `Box<str>`"
+)]
+pub trait Transform: Sized {
+    /// Returns the string that is transformed.
+    fn as_str(&self) -> &str;
+
+    /// Creates a value from the result of a transformation.
+    fn from_string(s: String) -> Self;
+
+    /// Removes the leading and trailing whitespace.
+    fn trim(&mut self) {
+        let s = self.as_str();
+        let trimmed = s.trim();
+        if trimmed.len() != s.len() {
+            *self = Self::from_string(trimmed.to_owned());
+        }
+    }
+
+    /// Converts the string to lowercase.
+    fn to_lower_case(&mut self) {
+        if !maps_to_itself(self.as_str(), char::is_ascii_uppercase, char::to_lowercase) {
+            *self = Self::from_string(self.as_str().to_lowercase());
+        }
+    }
+
+    /// Converts the string to uppercase.
+    fn to_upper_case(&mut self) {
+        if !maps_to_itself(self.as_str(), char::is_ascii_lowercase, char::to_uppercase) {
+            *self = Self::from_string(self.as_str().to_uppercase());
+        }
+    }
+
+    /// Converts the first character to uppercase, see `vale::capitalize`.
+    fn capitalize(&mut self) {
+        let s = self.as_str();
+        let first = s.chars().next().map_or("", |c| &s[..c.len_utf8()]);
+        if !maps_to_itself(first, char::is_ascii_lowercase, char::to_uppercase) {
+            *self = Self::from_string(capitalize(self.as_str()));
+        }
+    }
+
+    /// Shortens the string to at most `max_chars` characters, see `vale::truncate`.
+    fn truncate(&mut self, max_chars: usize) {
+        let s = self.as_str();
+        let truncated = truncate(s, max_chars);
+        if truncated.len() != s.len() {
+            *self = Self::from_string(truncated.to_owned());
+        }
+    }
+
+    /// Replaces the string with `default` if it is empty.
+    fn default_if_empty(&mut self, default: &str) {
+        if self.as_str().is_empty() {
+            *self = Self::from_string(default.to_owned());
+        }
+    }
+}
+
+impl Transform for String {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn from_string(s: String) -> Self {
+        s
+    }
+}
+
+impl Transform for Box<str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn from_string(s: String) -> Self {
+        s.into()
+    }
+}
+
+impl Transform for Rc<str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn from_string(s: String) -> Self {
+        s.into()
+    }
+}
+
+impl Transform for Arc<str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn from_string(s: String) -> Self {
+        s.into()
+    }
+}
+
+impl Transform for Cow<'_, str> {
+    fn as_str(&self) -> &str {
+        self
+    }
+
+    fn from_string(s: String) -> Self {
+        Cow::Owned(s)
+    }
+}
+
+impl<T: Transform> Transform for Box<T> {
+    fn as_str(&self) -> &str {
+        (**self).as_str()
+    }
+
+    fn from_string(s: String) -> Self {
+        Box::new(T::from_string(s))
+    }
+}
+
+impl<T: Transform> Transform for Rc<T> {
+    fn as_str(&self) -> &str {
+        (**self).as_str()
+    }
+
+    fn from_string(s: String) -> Self {
+        Rc::new(T::from_string(s))
+    }
+}
+
+impl<T: Transform> Transform for Arc<T> {
+    fn as_str(&self) -> &str {
+        (**self).as_str()
+    }
+
+    fn from_string(s: String) -> Self {
+        Arc::new(T::from_string(s))
+    }
+}
+
+/// Whether converting every character of `s` with `map` leaves it unchanged, in which case the
+/// conversion of the whole string can be skipped. For ascii characters, `changes_ascii` is checked
+/// instead, which is a lot cheaper.
+fn maps_to_itself<I: Iterator<Item = char>>(
+    s: &str,
+    changes_ascii: fn(&char) -> bool,
+    map: fn(char) -> I,
+) -> bool {
+    s.chars().all(|c| {
+        if c.is_ascii() {
+            return !changes_ascii(&c);
+        }
+        let mut mapped = map(c);
+        mapped.next() == Some(c) && mapped.next().is_none()
+    })
+}
+
+/// Returns a copy of the provided string where the first character is converted to uppercase. The
+/// rest of the string is left untouched. Note that some characters become multiple characters when
+/// uppercased, in which case all of them are included.
+///
+/// ### Example
+/// ```rust
+/// assert_eq!(vale::capitalize("john"), "John");
+/// assert_eq!(vale::capitalize(""), "");
+/// ```
+pub fn capitalize(s: &str) -> String {
+    let mut chars = s.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Returns the start of the provided string, containing at most `max_chars` characters. The string
+/// is cut on a character boundary, so unlike `String::truncate` this never panics on multibyte
+/// input.
+///
+/// ### Example
+/// ```rust
+/// assert_eq!(vale::truncate("hello world", 5), "hello");
+/// assert_eq!(vale::truncate("🧀🧀🧀", 2), "🧀🧀");
+/// assert_eq!(vale::truncate("short", 10), "short");
+/// ```
+pub fn truncate(s: &str, max_chars: usize) -> &str {
+    match s.char_indices().nth(max_chars) {
+        Some((idx, _)) => &s[..idx],
+        None => s,
+    }
+}
@@ -0,0 +1,215 @@
+/// Checks whether the provided string is a syntactically valid email address. This follows the
+/// definition used by HTML forms: a local part made up of letters, digits and the characters
+/// ``.!#$%&'*+/=?^_`{|}~-``, followed by an `@` and a domain consisting of one or more labels
+/// separated by dots. A label contains letters, digits and hyphens, but may not start or end with a
+/// hyphen. Dots may not appear at the start or end of the local part, or twice in a row. As in
+/// RFC 5321, the local part may be at most 64 characters long, and the domain at most 255.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_email("luuk@example.com"));
+/// assert!(!vale::is_email("luuk.example.com"));
+/// ```
+pub fn is_email(s: &str) -> bool {
+    let (local, domain) = match s.find('@') {
+        Some(idx) => (&s[..idx], &s[idx + 1..]),
+        None => return false,
+    };
+    let valid_local = local.len() <= 64
+        && local
+            .split('.')
+            .all(|part| !part.is_empty() && part.chars().all(is_local_char));
+    let valid_domain = domain.len() <= 255 && domain.split('.').all(is_domain_label);
+    valid_local && valid_domain
+}
+
+/// Checks whether the provided string is an absolute url, such as `https://example.com/path`.
+/// Relative urls like `/path` are rejected.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_url("https://example.com/path?query=1"));
+/// assert!(!vale::is_url("/path"));
+/// ```
+///
+/// ### Features
+/// Requires the `url` feature to be enabled
+#[cfg(feature = "url")]
+pub fn is_url(s: &str) -> bool {
+    rl::Url::parse(s).is_ok()
+}
+
+/// Checks whether the provided string matches the regular expression `pattern`, which is compiled
+/// on every call. This allows the pattern to be chosen at runtime, for example by another field of
+/// the validated struct, through `predicate`. An invalid pattern matches nothing, so `false` is
+/// returned rather than panicking. For a fixed pattern, prefer the `regex` validation, which checks
+/// the pattern at compile time and compiles it only once.
+///
+/// ### Example
+/// ```rust
+/// #[derive(vale::Validate)]
+/// struct Code {
+///     pattern: String,
+///     #[validate(predicate = "vale::regex_matches(&self.code, &self.pattern)")]
+///     code: String,
+/// }
+///
+/// assert!(vale::regex_matches("AB-12", "^[A-Z]{2}-[0-9]+$"));
+/// assert!(!vale::regex_matches("AB-12", "(unclosed"));
+///
+/// let mut code = Code { pattern: "^[0-9]{4}$".to_string(), code: "1234".to_string() };
+/// assert!(vale::Validate::validate(&mut code).is_ok());
+/// ```
+///
+/// ### Features
+/// Requires the `regex` feature to be enabled
+#[cfg(feature = "regex")]
+pub fn regex_matches(s: &str, pattern: &str) -> bool {
+    rgx::Regex::new(pattern).is_ok_and(|regex| regex.is_match(s))
+}
+
+/// Checks whether the provided string looks like a phone number. This is deliberately lenient: the
+/// number may start with a `+`, and may contain spaces, hyphens and parentheses besides its digits,
+/// of which there must be between 7 and 15.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_phone("+31 (0)20 123-4567"));
+/// assert!(!vale::is_phone("call me maybe"));
+/// ```
+///
+/// ### Features
+/// Requires the `phone` feature to be enabled
+#[cfg(feature = "phone")]
+pub fn is_phone(s: &str) -> bool {
+    let number = s.strip_prefix('+').unwrap_or(s);
+    let digits = number.chars().filter(char::is_ascii_digit).count();
+    let valid_chars = number.chars().all(|c| c.is_ascii_digit() || " -()".contains(c));
+    valid_chars && (7..=15).contains(&digits)
+}
+
+/// Checks whether the provided string is a phone number in the E.164 format, which is a `+`
+/// followed by at most 15 digits, the first of which is not a zero.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_e164("+14155552671"));
+/// assert!(!vale::is_e164("+1 415 555 2671"));
+/// ```
+///
+/// ### Features
+/// Requires the `phone` feature to be enabled
+#[cfg(feature = "phone")]
+pub fn is_e164(s: &str) -> bool {
+    let digits = match s.strip_prefix('+') {
+        Some(digits) => digits,
+        None => return false,
+    };
+    !digits.starts_with('0')
+        && (1..=15).contains(&digits.len())
+        && digits.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Checks whether the provided string is valid base64, using the standard alphabet with padding.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_base64("Y2hlZXNl"));
+/// assert!(!vale::is_base64("Y2hlZXNl!"));
+/// ```
+///
+/// ### Features
+/// Requires the `base64` feature to be enabled
+#[cfg(feature = "base64")]
+pub fn is_base64(s: &str) -> bool {
+    use b64::Engine;
+    b64::engine::general_purpose::STANDARD.decode(s).is_ok()
+}
+
+/// Checks whether the provided string is a UUID, such as `67e55044-10b1-426f-9247-bb680e5fe0c8`.
+/// Besides this hyphenated form, the forms without hyphens, between braces and prefixed with
+/// `urn:uuid:` are accepted as well.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_uuid("67e55044-10b1-426f-9247-bb680e5fe0c8"));
+/// assert!(!vale::is_uuid("67e55044-10b1-426f-9247"));
+/// ```
+///
+/// ### Features
+/// Requires the `uuid` feature to be enabled
+#[cfg(feature = "uuid")]
+pub fn is_uuid(s: &str) -> bool {
+    uid::Uuid::parse_str(s).is_ok()
+}
+
+/// Checks whether the provided string is a UUID of the provided version, such as `4` for randomly
+/// generated UUIDs. See `vale::is_uuid` for the accepted forms.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_uuid_version("67e55044-10b1-426f-9247-bb680e5fe0c8", 4));
+/// assert!(!vale::is_uuid_version("67e55044-10b1-126f-9247-bb680e5fe0c8", 4));
+/// ```
+///
+/// ### Features
+/// Requires the `uuid` feature to be enabled
+#[cfg(feature = "uuid")]
+pub fn is_uuid_version(s: &str, version: usize) -> bool {
+    matches!(uid::Uuid::parse_str(s), Ok(uuid) if uuid.get_version_num() == version)
+}
+
+/// Checks whether the provided string is a url slug, such as `my-post-1`. A slug consists of
+/// lowercase ascii letters and digits, which may be separated by hyphens. It may not start or end
+/// with a hyphen, or contain two hyphens in a row. The empty string is not a slug.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::is_slug("my-post-1"));
+/// assert!(!vale::is_slug("My_Post"));
+/// assert!(!vale::is_slug("a--b"));
+/// ```
+pub fn is_slug(s: &str) -> bool {
+    s.split('-').all(|part| {
+        !part.is_empty() && part.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+    })
+}
+
+/// Checks whether the provided string is a credit card number, which has between 12 and 19 digits
+/// and passes the Luhn checksum. Spaces and dashes are ignored, so `4111 1111 1111 1111` is
+/// accepted. Any other character that is not a digit makes the number invalid.
+///
+/// ### Example
+/// ```rust
+/// assert!(vale::luhn_valid("4111 1111 1111 1111"));
+/// assert!(!vale::luhn_valid("4111-1111-1111-1112"));
+/// ```
+pub fn luhn_valid(s: &str) -> bool {
+    let mut sum = 0;
+    let mut count = 0;
+    for c in s.chars().rev().filter(|c| *c != ' ' && *c != '-') {
+        let digit = match c.to_digit(10) {
+            Some(digit) => digit,
+            None => return false,
+        };
+        sum += match count % 2 {
+            0 => digit,
+            _ if digit > 4 => digit * 2 - 9,
+            _ => digit * 2,
+        };
+        count += 1;
+    }
+    (12..=19).contains(&count) && sum % 10 == 0
+}
+
+fn is_local_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || "!#$%&'*+/=?^_`{|}~-".contains(c)
+}
+
+fn is_domain_label(label: &str) -> bool {
+    !label.is_empty()
+        && label.len() <= 63
+        && !label.starts_with('-')
+        && !label.ends_with('-')
+        && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+}
@@ -0,0 +1,98 @@
+use crate::{ErrorBody, ResponseFormat};
+use serde::de::DeserializeOwned;
+use wrp::http::header::CONTENT_TYPE;
+use wrp::http::StatusCode;
+use wrp::reject::{Reject, Rejection};
+use wrp::{Filter, Reply};
+
+/// A filter that parses the request body as JSON, and validates the result. If the body can not be
+/// parsed, the request is rejected in the same way `warp::body::json` rejects it. If the validation
+/// fails, the request is rejected with a `ValidationRejection`, which can be turned into a response
+/// using `recover`.
+///
+/// ### Example
+/// ```rust
+/// # extern crate wrp as warp;
+/// # #[derive(vale::Validate, serde::Deserialize)]
+/// # struct User {}
+/// use warp::Filter;
+///
+/// let route = warp::post()
+///     .and(warp::path("user"))
+///     .and(vale::warp::validated_json::<User>())
+///     .map(|user: User| {
+///         // user is now validated, this code is not reached if the validation failed
+///         warp::reply()
+///     })
+///     .recover(vale::warp::recover);
+/// ```
+///
+/// ### Features
+/// Requires the `warp` feature to be enabled
+pub fn validated_json<T>() -> impl Filter<Extract = (T,), Error = Rejection> + Clone
+where
+    T: DeserializeOwned + crate::Validate + Send,
+{
+    validated_json_with(ResponseFormat::default())
+}
+
+/// Like `validated_json`, but the errors of a failed validation are sent in the provided
+/// `ResponseFormat` by `recover`, instead of as a JSON array.
+///
+/// ### Features
+/// Requires the `warp` feature to be enabled
+pub fn validated_json_with<T>(
+    format: ResponseFormat,
+) -> impl Filter<Extract = (T,), Error = Rejection> + Clone
+where
+    T: DeserializeOwned + crate::Validate + Send,
+{
+    wrp::body::json::<T>().and_then(move |mut inner: T| async move {
+        match format.validate(&mut inner) {
+            Ok(()) => Ok(inner),
+            Err(body) => Err(wrp::reject::custom(ValidationRejection { body })),
+        }
+    })
+}
+
+/// The rejection that is produced by `validated_json` when the body was parsed, but did not pass
+/// validation.
+#[derive(Debug)]
+pub struct ValidationRejection {
+    body: ErrorBody,
+}
+
+impl ValidationRejection {
+    /// Returns the list of errors that were produced by the validation.
+    pub fn errors(&self) -> &[String] {
+        self.body.errors()
+    }
+
+    /// Returns the body of the response, in the `ResponseFormat` of the filter.
+    pub fn body(&self) -> &ErrorBody {
+        &self.body
+    }
+}
+
+impl Reject for ValidationRejection {}
+
+/// Turns a `ValidationRejection` into a `400 Bad Request` with the validation errors as the body,
+/// which is a JSON array unless a different format was passed to `validated_json_with`. Any other
+/// rejection is passed on unchanged, so it can be used directly with
+/// `Filter::recover`.
+///
+/// ### Features
+/// Requires the `warp` feature to be enabled
+pub async fn recover(rejection: Rejection) -> Result<impl Reply, Rejection> {
+    match rejection.find::<ValidationRejection>() {
+        Some(rejection) => {
+            let body = wrp::reply::with_header(
+                rejection.body.body(),
+                CONTENT_TYPE,
+                rejection.body.content_type(),
+            );
+            Ok(wrp::reply::with_status(body, StatusCode::BAD_REQUEST))
+        }
+        None => Err(rejection),
+    }
+}
@@ -0,0 +1,82 @@
+#![cfg(feature = "actix")]
+
+use actix_web::http::StatusCode;
+use actix_web::{test, web, App};
+use vale::actix::Valid;
+use vale::ResponseFormat;
+
+#[derive(vale::Validate)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Struct {
+    #[validate(gt(10))]
+    value: u32,
+    #[validate(trim, len_lt(10), to_lower_case)]
+    transformer: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        value: 12,
+        transformer: "hello".to_string(),
+    }
+}
+
+async fn route(to_validate: Valid<Struct>) -> web::Json<Struct> {
+    web::Json(to_validate.into_inner())
+}
+
+#[actix_web::test]
+async fn test_valid() {
+    let app = test::init_service(App::new().route("/", web::post().to(route))).await;
+    let req = test::TestRequest::post().uri("/").set_json(valid_struct()).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[actix_web::test]
+async fn test_transform() {
+    let app = test::init_service(App::new().route("/", web::post().to(route))).await;
+    let mut s = valid_struct();
+    s.transformer = "   CAST ME   ".to_string();
+    let req = test::TestRequest::post().uri("/").set_json(s).to_request();
+    let resp: Struct = test::call_and_read_body_json(&app, req).await;
+    assert_eq!(resp.transformer, "cast me");
+}
+
+#[actix_web::test]
+async fn test_invalid() {
+    let app = test::init_service(App::new().route("/", web::post().to(route))).await;
+    let mut s = valid_struct();
+    s.value = 8;
+    let req = test::TestRequest::post().uri("/").set_json(s).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    let body: Vec<String> = test::read_body_json(resp).await;
+    assert_eq!(body, vec!["Failed to validate field `value`, value too low".to_string()]);
+}
+
+#[actix_web::test]
+async fn test_text_format() {
+    let app = App::new().app_data(ResponseFormat::Text).route("/", web::post().to(route));
+    let app = test::init_service(app).await;
+    let mut s = valid_struct();
+    s.value = 8;
+    let req = test::TestRequest::post().uri("/").set_json(s).to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers().get("content-type").unwrap(), "text/plain; charset=utf-8");
+    let body = test::read_body(resp).await;
+    assert_eq!(body, "Failed to validate field `value`, value too low");
+}
+
+#[actix_web::test]
+async fn test_malformed() {
+    let app = test::init_service(App::new().route("/", web::post().to(route))).await;
+    let req = test::TestRequest::post()
+        .uri("/")
+        .insert_header(("content-type", "application/json"))
+        .set_payload("{")
+        .to_request();
+    let resp = test::call_service(&app, req).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
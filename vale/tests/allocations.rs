@@ -0,0 +1,137 @@
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::collections::HashMap;
+
+use vale::Validate;
+
+/// Counts the allocations made by the current thread, so that tests running in parallel don't
+/// influence each other.
+struct Counting;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let _ = ALLOCATIONS.try_with(|count| count.set(count.get() + 1));
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: Counting = Counting;
+
+fn allocations(f: impl FnOnce()) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    f();
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[derive(vale::Validate)]
+struct Address {
+    #[validate(trim, len_gt(0))]
+    street: String,
+    #[validate(to_upper_case, len_eq(2))]
+    country: String,
+}
+
+#[derive(vale::Validate)]
+struct User {
+    #[validate(trim, to_lower_case, len_range(3, 20), ascii_alphanumeric)]
+    name: String,
+    #[validate(capitalize, truncate(10), not_empty)]
+    display_name: String,
+    #[validate(email)]
+    email: String,
+    #[validate(gte(18), lt(150))]
+    age: u32,
+    #[validate(each(gt(0)), max_items(5))]
+    scores: Vec<i32>,
+    #[validate(each_value(len_gt(0)))]
+    settings: HashMap<String, String>,
+    #[validate(len_gt(0))]
+    nickname: Option<String>,
+    #[validate(nested)]
+    address: Address,
+}
+
+struct Login {
+    name: String,
+    password: String,
+}
+
+impl Validate for Login {
+    #[vale::ruleset]
+    #[inline]
+    fn validate(&mut self) -> vale::Result {
+        vale::rule!(!self.name.is_empty(), "Name is empty");
+        vale::rule!(self.password.len() >= 8, "Password of {} is too short", self.name);
+    }
+}
+
+fn valid_user() -> User {
+    let mut settings = HashMap::new();
+    settings.insert("theme".to_string(), "dark".to_string());
+    User {
+        name: "luuk".to_string(),
+        display_name: "Luuk".to_string(),
+        email: "luuk@example.com".to_string(),
+        age: 30,
+        scores: vec![1, 2, 3],
+        settings,
+        nickname: Some("cheese".to_string()),
+        address: Address { street: "Dam 1".to_string(), country: "NL".to_string() },
+    }
+}
+
+#[test]
+fn test_valid_does_not_allocate() {
+    let mut user = valid_user();
+    assert_eq!(allocations(|| assert!(user.validate().is_ok())), 0);
+    assert_eq!(allocations(|| assert!(user.validate_fields().is_ok())), 0);
+    assert_eq!(allocations(|| assert!(user.validate_nested("user").is_ok())), 0);
+
+    let mut login = Login { name: "luuk".to_string(), password: "correct horse".to_string() };
+    assert_eq!(allocations(|| assert!(login.validate().is_ok())), 0);
+}
+
+#[test]
+fn test_transformers_change_value() {
+    let mut user = valid_user();
+    user.name = "  LUUK ".to_string();
+    user.display_name = "luuk wester".to_string();
+    user.address.country = "nl".to_string();
+    user.validate().unwrap();
+    assert_eq!(user.name, "luuk");
+    assert_eq!(user.display_name, "Luuk weste");
+    assert_eq!(user.address.country, "NL");
+}
+
+#[test]
+fn test_invalid() {
+    let mut user = valid_user();
+    user.age = 12;
+    user.scores.push(0);
+    assert!(allocations(|| {
+        assert_eq!(
+            user.validate().unwrap_err(),
+            [
+                "Failed to validate field `age`, value must be at least 18",
+                "Failed to validate field `scores[3]`, value too low",
+            ],
+        );
+    }) > 0);
+
+    let mut login = Login { name: "luuk".to_string(), password: "hunter2".to_string() };
+    assert_eq!(login.validate().unwrap_err(), ["Password of luuk is too short"]);
+}
@@ -0,0 +1,105 @@
+use vale::Validate;
+
+/// A struct whose fields carry several `#[validate]` attributes, mixed with doc comments.
+#[derive(Validate)]
+struct Merged {
+    /// Both attributes apply.
+    #[validate(gt(0))]
+    #[validate(lt(100))]
+    value: i32,
+    /// The transformer of the first attribute runs before the validation of the second.
+    #[validate(trim)]
+    #[validate(not_empty)]
+    name: String,
+}
+
+#[test]
+fn test_merged_valid() {
+    let mut s = Merged { value: 50, name: " name ".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.name, "name");
+}
+
+#[test]
+fn test_merged_attributes_compose() {
+    let mut s = Merged { value: 100, name: "name".to_string() };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `value`, value too high".to_string()]),
+    );
+    let mut s = Merged { value: 0, name: "   ".to_string() };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `value`, value too low".to_string(),
+            "Failed to validate field `name`, value must not be empty".to_string(),
+        ]),
+    );
+}
+
+#[cfg(feature = "serde")]
+mod serde_attributes {
+    use vale::Validate;
+
+    /// A struct whose fields mix `#[validate]` with other attributes.
+    #[derive(Validate, serde::Deserialize)]
+    struct Struct {
+        /// Both attributes apply.
+        #[validate(gt(0))]
+        #[serde(default)]
+        #[validate(lt(100))]
+        value: i32,
+        #[serde(rename = "label")]
+        #[validate(trim)]
+        #[validate(not_empty)]
+        name: String,
+    }
+
+    #[test]
+    fn test_valid() {
+        let mut s: Struct = serde_json::from_str(r#"{"value": 50, "label": " name "}"#).unwrap();
+        s.validate().unwrap();
+        assert_eq!(s.name, "name");
+    }
+
+    #[test]
+    fn test_attributes_compose() {
+        let mut s: Struct = serde_json::from_str(r#"{"value": 100, "label": "name"}"#).unwrap();
+        assert_eq!(
+            s.validate(),
+            Err(vec!["Failed to validate field `value`, value too high".to_string()]),
+        );
+        let mut s: Struct = serde_json::from_str(r#"{"label": "   "}"#).unwrap();
+        assert_eq!(
+            s.validate(),
+            Err(vec![
+                "Failed to validate field `value`, value too low".to_string(),
+                "Failed to validate field `name`, value must not be empty".to_string(),
+            ]),
+        );
+    }
+
+    /// An enum whose variant fields carry doc comments and `serde` attributes.
+    #[derive(Validate, serde::Deserialize)]
+    #[serde(tag = "kind")]
+    enum Shape {
+        /// A circle.
+        Circle {
+            /// The radius of the circle.
+            #[serde(rename = "r")]
+            #[validate(gt(0.0))]
+            radius: f64,
+        },
+    }
+
+    #[test]
+    fn test_enum_variant_attributes() {
+        let mut shape: Shape = serde_json::from_str(r#"{"kind": "Circle", "r": 1.5}"#).unwrap();
+        shape.validate().unwrap();
+        let mut shape: Shape = serde_json::from_str(r#"{"kind": "Circle", "r": 0.0}"#).unwrap();
+        assert_eq!(
+            shape.validate(),
+            Err(vec!["Failed to validate field `radius`, value too low".to_string()]),
+        );
+    }
+}
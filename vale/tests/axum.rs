@@ -0,0 +1,135 @@
+#![cfg(feature = "axum")]
+
+use axm::body::Body;
+use axm::http::{Request, StatusCode};
+use axm::routing::post;
+use axm::{Extension, Json, Router};
+use tower::ServiceExt;
+use vale::axum::{Valid, ValidationRejection};
+use vale::ResponseFormat;
+
+#[derive(vale::Validate)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Struct {
+    #[validate(gt(10))]
+    value: u32,
+    #[validate(trim, len_lt(10), to_lower_case)]
+    transformer: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        value: 12,
+        transformer: "hello".to_string(),
+    }
+}
+
+async fn route(to_validate: Valid<Struct>) -> Json<Struct> {
+    Json(to_validate.into_inner())
+}
+
+fn app() -> Router {
+    Router::new().route("/", post(route))
+}
+
+fn request(s: &Struct) -> Request<Body> {
+    Request::builder()
+        .method("POST")
+        .uri("/")
+        .header("content-type", "application/json")
+        .body(Body::from(serde_json::to_string(s).unwrap()))
+        .unwrap()
+}
+
+async fn body_string(body: Body) -> String {
+    let bytes = axm::body::to_bytes(body, usize::MAX).await.unwrap();
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+#[tokio::test]
+async fn test_valid() {
+    let resp = app().oneshot(request(&valid_struct())).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_transform() {
+    let mut s = valid_struct();
+    s.transformer = "   CAST ME   ".to_string();
+    let resp = app().oneshot(request(&s)).await.unwrap();
+    let resp: Struct = serde_json::from_str(&body_string(resp.into_body()).await).unwrap();
+    assert_eq!(resp.transformer, "cast me");
+}
+
+#[tokio::test]
+async fn test_invalid() {
+    let mut s = valid_struct();
+    s.value = 8;
+    let resp = app().oneshot(request(&s)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        body_string(resp.into_body()).await,
+        r#"["Failed to validate field `value`, value too low"]"#,
+    );
+}
+
+#[tokio::test]
+async fn test_json_object_format() {
+    let mut s = valid_struct();
+    s.value = 8;
+    let app = app().layer(Extension(ResponseFormat::JsonObject));
+    let resp = app.oneshot(request(&s)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()["content-type"], "application/json");
+    assert_eq!(
+        body_string(resp.into_body()).await,
+        r#"{"value":["Failed to validate field `value`, value too low"]}"#,
+    );
+}
+
+#[tokio::test]
+async fn test_text_format() {
+    let s = Struct {
+        value: 8,
+        transformer: "far too long".to_string(),
+    };
+    let app = app().layer(Extension(ResponseFormat::Text));
+    let resp = app.oneshot(request(&s)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()["content-type"], "text/plain; charset=utf-8");
+    assert_eq!(
+        body_string(resp.into_body()).await,
+        "Failed to validate field `value`, value too low\n\
+         Failed to validate field `transformer`, value too long",
+    );
+}
+
+#[tokio::test]
+async fn test_malformed() {
+    let req = Request::builder()
+        .method("POST")
+        .uri("/")
+        .header("content-type", "application/json")
+        .body(Body::from("{"))
+        .unwrap();
+    let resp = app().oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
+
+async fn mapped_route(to_validate: Result<Valid<Struct>, ValidationRejection>) -> StatusCode {
+    match to_validate {
+        Ok(_) => StatusCode::OK,
+        Err(ValidationRejection::ValidationError(_)) => StatusCode::UNPROCESSABLE_ENTITY,
+        Err(ValidationRejection::FormattedError(_)) => StatusCode::UNPROCESSABLE_ENTITY,
+        Err(ValidationRejection::JsonError(_)) => StatusCode::BAD_REQUEST,
+    }
+}
+
+#[tokio::test]
+async fn test_mapped_rejection() {
+    let mut s = valid_struct();
+    s.value = 8;
+    let app = Router::new().route("/", post(mapped_route));
+    let resp = app.oneshot(request(&s)).await.unwrap();
+    assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+}
@@ -0,0 +1,36 @@
+use vale::{Validate, Validator};
+
+struct Entity {
+    id: i32,
+    name: String,
+}
+
+impl Validate for Entity {
+    fn validate(&mut self) -> vale::Result {
+        Validator::new()
+            .rule(self.id > 0, "`id` is nonpositive")
+            .rule(!self.name.is_empty(), "`name` is empty")
+            .rule(self.name.len() < 5, format!("`name` is {} bytes long", self.name.len()))
+            .finish()
+    }
+}
+
+#[test]
+fn test_valid() {
+    Entity { id: 1, name: "abc".to_string() }.validate().unwrap();
+    assert_eq!(Validator::new().finish(), Ok(()));
+}
+
+#[test]
+fn test_collects_errors() {
+    let mut e = Entity { id: 0, name: "abcdef".to_string() };
+    assert_eq!(
+        e.validate(),
+        Err(vec!["`id` is nonpositive".to_string(), "`name` is 6 bytes long".to_string()]),
+    );
+    let mut e = Entity { id: -1, name: String::new() };
+    assert_eq!(
+        e.validate(),
+        Err(vec!["`id` is nonpositive".to_string(), "`name` is empty".to_string()]),
+    );
+}
@@ -0,0 +1,58 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(bytes_len_lt(6))]
+    text: String,
+    #[validate(bytes_len_eq(4))]
+    payload: Vec<u8>,
+    #[validate(bytes_len_range(2, 3))]
+    range: String,
+    #[validate(len_gt(1, count = "bytes"))]
+    counted: Vec<u8>,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        text: "abcde".to_string(),
+        payload: vec![0, 1, 2, 3],
+        range: "é".to_string(),
+        counted: vec![0xff, 0xfe],
+    }
+}
+
+#[test]
+fn test_valid() {
+    valid_struct().validate().unwrap();
+}
+
+#[test]
+fn test_multibyte_string() {
+    let mut s = valid_struct();
+    s.range = "€".to_string();
+    s.validate().unwrap();
+    // three characters, but six bytes
+    s.text = "äöü".to_string();
+    s.range = "€€".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `text`, value too long".to_string(),
+            "Failed to validate field `range`, length out of range".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_byte_vector() {
+    let mut s = valid_struct();
+    s.payload = vec![0; 5];
+    s.counted = vec![0];
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `payload`, value of incorrect length".to_string(),
+            "Failed to validate field `counted`, value too short".to_string(),
+        ]),
+    );
+}
@@ -0,0 +1,50 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(alphanumeric)]
+    username: String,
+    #[validate(ascii)]
+    code: String,
+    #[validate(numeric)]
+    pin: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        username: "ThouCheese42".to_string(),
+        code: "a-b_c!".to_string(),
+        pin: "0123".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    let mut s = Struct { username: "Ünïcödé".to_string(), ..valid_struct() };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_empty_passes() {
+    let mut s = Struct { username: String::new(), code: String::new(), pin: String::new() };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    let mut s = Struct {
+        username: "thou cheese".to_string(),
+        code: "kaas🧀".to_string(),
+        pin: "12a4".to_string(),
+    };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `username`, value is not alphanumeric".to_string(),
+            "Failed to validate field `code`, value is not ascii".to_string(),
+            "Failed to validate field `pin`, value is not numeric".to_string(),
+        ]),
+    );
+}
@@ -0,0 +1,50 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Inclusive {
+    #[validate(gte(18))]
+    age: u32,
+    #[validate(lte(100))]
+    score: u32,
+    #[validate(ne(0))]
+    divisor: i32,
+}
+
+#[derive(Validate)]
+struct Exclusive {
+    #[validate(gt(18))]
+    age: u32,
+    #[validate(lt(100))]
+    score: u32,
+}
+
+#[test]
+fn test_boundaries_inclusive() {
+    let mut s = Inclusive { age: 18, score: 100, divisor: 1 };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_boundaries_exclusive() {
+    let mut s = Exclusive { age: 18, score: 100 };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `age`, value too low".to_string(),
+            "Failed to validate field `score`, value too high".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_outside_inclusive() {
+    let mut s = Inclusive { age: 17, score: 101, divisor: 0 };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `age`, value must be at least 18".to_string(),
+            "Failed to validate field `score`, value must be at most 100".to_string(),
+            "Failed to validate field `divisor`, value not allowed".to_string(),
+        ]),
+    );
+}
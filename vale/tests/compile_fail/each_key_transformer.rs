@@ -0,0 +1,9 @@
+use std::collections::HashMap;
+
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(each_key(trim))]
+    labels: HashMap<String, String>,
+}
+
+fn main() {}
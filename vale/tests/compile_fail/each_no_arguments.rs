@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(each())]
+    scores: Vec<u8>,
+}
+
+fn main() {}
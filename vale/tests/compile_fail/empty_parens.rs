@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(gt())]
+    age: u32,
+}
+
+fn main() {}
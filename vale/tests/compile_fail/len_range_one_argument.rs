@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(len_range(1))]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+#[derive(vale::Validate)]
+#[validate(max_errors = 0)]
+struct Struct {
+    #[validate(gt(0))]
+    age: u32,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(gt(0, message_fn = "describe_age"))]
+    age: u32,
+}
+
+fn describe_age(age: &u32) -> String {
+    format!("{} is too young", age)
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(gt(0, message = 42))]
+    age: u32,
+}
+
+fn main() {}
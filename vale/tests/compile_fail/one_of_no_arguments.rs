@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(one_of())]
+    role: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(regex("[a-z"))]
+    username: String,
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(required)]
+    name: String,
+}
+
+fn main() {}
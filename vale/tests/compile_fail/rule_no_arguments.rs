@@ -0,0 +1,4 @@
+fn main() {
+    let mut errors: Vec<String> = Vec::new();
+    vale::rule_into!(errors,);
+}
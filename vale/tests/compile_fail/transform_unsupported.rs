@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(trim)]
+    count: u32,
+}
+
+fn main() {}
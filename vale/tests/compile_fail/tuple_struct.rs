@@ -0,0 +1,4 @@
+#[derive(vale::Validate)]
+struct Tuple(#[validate(gt(0))] u32);
+
+fn main() {}
@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+union Union {
+    number: u32,
+    float: f32,
+}
+
+fn main() {}
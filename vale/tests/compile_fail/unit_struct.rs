@@ -0,0 +1,4 @@
+#[derive(vale::Validate)]
+struct Unit;
+
+fn main() {}
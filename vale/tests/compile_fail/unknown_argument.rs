@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(gt(0), lt = 10)]
+    age: u32,
+}
+
+fn main() {}
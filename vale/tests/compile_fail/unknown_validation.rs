@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(len_lt(20), emial)]
+    email: String,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(trim)]
+    username: String,
+}
+
+fn main() {
+    let s = Struct { username: "ThouCheese".to_string() };
+    let _ = vale::ValidateRef::validate_ref(&s);
+}
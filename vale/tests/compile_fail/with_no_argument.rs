@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(with)]
+    age: u32,
+}
+
+fn main() {}
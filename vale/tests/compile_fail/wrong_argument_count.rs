@@ -0,0 +1,7 @@
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(range(1))]
+    age: u32,
+}
+
+fn main() {}
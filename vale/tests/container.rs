@@ -0,0 +1,61 @@
+use vale::Validate;
+
+#[derive(Validate)]
+#[validate(with(passwords_match))]
+struct Signup {
+    #[validate(trim)]
+    password: String,
+    #[validate(trim)]
+    password_confirm: String,
+}
+
+fn passwords_match(signup: &mut Signup) -> bool {
+    signup.password == signup.password_confirm
+}
+
+/// Doc comments on the struct are ignored.
+#[derive(Validate)]
+#[validate(try_with(check_range), with(is_short, message = "range is too long"))]
+struct Range {
+    #[validate(gt(0))]
+    start: u32,
+    end: u32,
+}
+
+fn check_range(range: &mut Range) -> Result<(), String> {
+    if range.start > range.end {
+        return Err(format!("range {}..{} is empty", range.start, range.end));
+    }
+    Ok(())
+}
+
+fn is_short(range: &mut Range) -> bool {
+    range.end - range.start.min(range.end) < 10
+}
+
+#[test]
+fn test_valid() {
+    let mut s = Signup { password: "hunter2 ".to_string(), password_confirm: "hunter2".to_string() };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    let mut s = Signup { password: "hunter2".to_string(), password_confirm: "hunter3".to_string() };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate `Signup`, value did not pass test".to_string()]),
+    );
+}
+
+#[test]
+fn test_try_with() {
+    let mut r = Range { start: 3, end: 5 };
+    r.validate().unwrap();
+    let mut r = Range { start: 0, end: 5 };
+    assert_eq!(r.validate(), Err(vec!["Failed to validate field `start`, value too low".to_string()]));
+    let mut r = Range { start: 6, end: 5 };
+    assert_eq!(r.validate(), Err(vec!["range 6..5 is empty".to_string()]));
+    let mut r = Range { start: 1, end: 20 };
+    assert_eq!(r.validate(), Err(vec!["range is too long".to_string()]));
+}
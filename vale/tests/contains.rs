@@ -0,0 +1,51 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(contains("@"))]
+    handle: String,
+    #[validate(starts_with("https://"))]
+    url: String,
+    #[validate(ends_with(".com"))]
+    domain: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        handle: "@luuk".to_string(),
+        url: "https://example.com".to_string(),
+        domain: "example.com".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `handle`, value does not contain \\\"@\\\"\"]")]
+fn test_contains() {
+    let mut s = valid_struct();
+    s.handle = "luuk".to_string();
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `url`, value does not start with \\\"https://\\\"\"]")]
+fn test_starts_with() {
+    let mut s = valid_struct();
+    s.url = "http://example.com".to_string();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_ends_with() {
+    let mut s = valid_struct();
+    s.domain = "example.org".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `domain`, value does not end with \".com\"".to_string()]),
+    );
+}
@@ -0,0 +1,42 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(len_lt(4, count = "chars"))]
+    chars: String,
+    #[validate(len_range(2, 3, count = "chars"))]
+    chars_range: String,
+    #[validate(len_lt(4, count = "len"))]
+    bytes: String,
+}
+
+#[test]
+fn test_multibyte() {
+    let mut s = Struct {
+        chars: "ééé".to_string(),
+        chars_range: "日本".to_string(),
+        bytes: "abc".to_string(),
+    };
+    s.validate().unwrap();
+    s.bytes = "ééé".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `bytes`, value too long".to_string()]),
+    );
+}
+
+#[test]
+fn test_too_many_chars() {
+    let mut s = Struct {
+        chars: "éééé".to_string(),
+        chars_range: "日".to_string(),
+        bytes: "abc".to_string(),
+    };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `chars`, value too long".to_string(),
+            "Failed to validate field `chars_range`, length out of range".to_string(),
+        ]),
+    );
+}
@@ -0,0 +1,48 @@
+use std::borrow::Cow;
+use vale::Validate;
+
+#[derive(Validate)]
+struct Comment<'a> {
+    #[validate(trim, len_gt(0))]
+    body: Cow<'a, str>,
+    #[validate(to_lower_case, eq("rust"))]
+    tag: Cow<'a, str>,
+}
+
+#[test]
+fn test_borrowed() {
+    let mut comment = Comment { body: Cow::Borrowed("hello"), tag: Cow::Borrowed("rust") };
+    comment.validate().unwrap();
+    // a transformer that does not change the value leaves it borrowed
+    assert!(matches!(comment.body, Cow::Borrowed("hello")));
+    assert!(matches!(comment.tag, Cow::Borrowed("rust")));
+
+    let mut comment = Comment { body: Cow::Borrowed("  hello "), tag: Cow::Borrowed("RUST") };
+    comment.validate().unwrap();
+    assert!(matches!(&comment.body, Cow::Owned(body) if body == "hello"));
+    assert!(matches!(&comment.tag, Cow::Owned(tag) if tag == "rust"));
+}
+
+#[test]
+fn test_owned() {
+    let mut comment = Comment {
+        body: Cow::Owned(" hello ".to_string()),
+        tag: Cow::Owned("Rust".to_string()),
+    };
+    comment.validate().unwrap();
+    assert_eq!(comment.body, "hello");
+    assert_eq!(comment.tag, "rust");
+}
+
+#[test]
+fn test_invalid() {
+    let mut comment = Comment { body: Cow::Borrowed("   "), tag: Cow::Owned("go".to_string()) };
+    assert_eq!(
+        comment.validate().unwrap_err(),
+        [
+            "Failed to validate field `body`, value too short",
+            "Failed to validate field `tag`, value incorrect",
+        ],
+    );
+    assert_eq!(comment.body, "");
+}
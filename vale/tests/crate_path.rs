@@ -0,0 +1,49 @@
+mod renamed {
+    // here, `vale` refers to this module rather than the crate, like it would when the dependency
+    // is renamed
+    mod vale {}
+
+    use ::vale as my_vale;
+    use my_vale::Validate;
+
+    #[derive(my_vale::Validate)]
+    #[validate(crate = my_vale)]
+    #[validate(with(|user: &mut User| user.name != user.email))]
+    pub struct User {
+        #[validate(trim, len_lt(10))]
+        pub name: String,
+        #[validate(email)]
+        pub email: String,
+    }
+
+    #[derive(my_vale::Validate)]
+    #[validate(crate = self::my_vale)]
+    pub enum Login {
+        Email(#[validate(email)] String),
+        Token(#[validate(hex, len_eq(8))] String),
+    }
+
+    #[test]
+    fn test_struct() {
+        let mut user = User { name: " Luuk ".to_string(), email: "luuk@example.com".to_string() };
+        user.validate().unwrap();
+        assert_eq!(user.name, "Luuk");
+
+        let mut user = User { name: "Luuk".to_string(), email: "Luuk".to_string() };
+        let errors = user.validate().unwrap_err();
+        assert_eq!(
+            errors,
+            [
+                "Failed to validate field `email`, not a valid email address",
+                "Failed to validate `User`, value did not pass test",
+            ],
+        );
+    }
+
+    #[test]
+    fn test_enum() {
+        Login::Token("c0ffee00".to_string()).validate().unwrap();
+        let errors = Login::Email("cheese".to_string()).validate().unwrap_err();
+        assert_eq!(errors, ["Failed to validate field `0`, not a valid email address"]);
+    }
+}
@@ -0,0 +1,44 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Payment {
+    #[validate(credit_card)]
+    card_number: String,
+}
+
+#[test]
+fn valid_card_numbers() {
+    for number in ["4111111111111111", "4111 1111 1111 1111", "5500-0000-0000-0004", "378282246310005"] {
+        let mut s = Payment { card_number: number.to_string() };
+        s.validate().unwrap();
+    }
+}
+
+#[test]
+fn invalid_card_number() {
+    let mut s = Payment { card_number: "4111 1111 1111 1112".to_string() };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `card_number`, not a valid card number".to_string()]),
+    );
+}
+
+#[test]
+fn luhn() {
+    assert!(vale::luhn_valid("4111111111111111"));
+    assert!(!vale::luhn_valid("4111111111111112"));
+    assert!(!vale::luhn_valid("4111 1111 1111 111a"));
+}
+
+#[test]
+fn luhn_length() {
+    // these pass the checksum, but are too short or too long to be a card number
+    assert!(!vale::luhn_valid("0"));
+    assert!(!vale::luhn_valid("00"));
+    assert!(!vale::luhn_valid("0000"));
+    assert!(!vale::luhn_valid("79927398713"));
+    assert!(!vale::luhn_valid("00000000000000000000"));
+    assert!(!vale::luhn_valid(""));
+    assert!(vale::luhn_valid("000000000000"));
+    assert!(vale::luhn_valid("0000000000000000000"));
+}
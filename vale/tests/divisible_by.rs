@@ -0,0 +1,26 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(divisible_by(5))]
+    quantity: u32,
+    #[validate(divisible_by(1))]
+    anything: i64,
+}
+
+#[test]
+fn test_multiple() {
+    let mut s = Struct { quantity: 25, anything: 3 };
+    s.validate().unwrap();
+    let mut s = Struct { quantity: 0, anything: -7 };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_not_multiple() {
+    let mut s = Struct { quantity: 12, anything: 3 };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `quantity`, not a multiple of 5".to_string()]),
+    );
+}
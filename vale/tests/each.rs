@@ -0,0 +1,56 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(each(len_lt(6)))]
+    tags: Vec<String>,
+    #[validate(len_lt(4), each(trim, to_lower_case))]
+    names: Vec<String>,
+    #[validate(each(gt(0, message = "scores must be positive")))]
+    scores: Vec<i32>,
+    #[validate(each(each(lt(10))))]
+    grid: Vec<Vec<u8>>,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        tags: vec!["rust".to_string(), "web".to_string()],
+        names: vec![" Luuk ".to_string(), "Vale".to_string()],
+        scores: vec![1, 2, 3],
+        grid: vec![vec![1, 2], vec![3]],
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    assert_eq!(s.names, vec!["luuk".to_string(), "vale".to_string()]);
+}
+
+#[test]
+fn test_one_invalid() {
+    let mut s = valid_struct();
+    s.tags.push("validation".to_string());
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `tags[2]`, value too long".to_string()]),
+    );
+}
+
+#[test]
+fn test_message() {
+    let mut s = valid_struct();
+    s.scores[1] = -1;
+    assert_eq!(s.validate(), Err(vec!["scores must be positive".to_string()]));
+}
+
+#[test]
+fn test_nested_collections() {
+    let mut s = valid_struct();
+    s.grid[1].push(12);
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `grid[1][1]`, value too high".to_string()]),
+    );
+}
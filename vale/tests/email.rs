@@ -0,0 +1,53 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(email)]
+    email: String,
+}
+
+#[test]
+fn test_valid() {
+    let mut s = Struct { email: "luuk@example.com".to_string() };
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `email`, not a valid email address\"]")]
+fn test_invalid() {
+    let mut s = Struct { email: "luuk.example.com".to_string() };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_valid_addresses() {
+    assert!(vale::is_email("luuk@example.com"));
+    assert!(vale::is_email("first.last@example.com"));
+    assert!(vale::is_email("user+tag@sub.example.co.uk"));
+    assert!(vale::is_email("o'brien@example.org"));
+    assert!(vale::is_email("admin@localhost"));
+    assert!(vale::is_email("x@my-domain.com"));
+}
+
+#[test]
+fn test_invalid_addresses() {
+    assert!(!vale::is_email(""));
+    assert!(!vale::is_email("example.com"));
+    assert!(!vale::is_email("@example.com"));
+    assert!(!vale::is_email("luuk@"));
+    assert!(!vale::is_email("luuk@example.com."));
+    assert!(!vale::is_email("luuk.@example.com"));
+    assert!(!vale::is_email(".luuk@example.com"));
+    assert!(!vale::is_email("lu..uk@example.com"));
+    assert!(!vale::is_email("luuk@exa..mple.com"));
+    assert!(!vale::is_email("luuk@-example.com"));
+    assert!(!vale::is_email("luuk@example@com"));
+    assert!(!vale::is_email("lu uk@example.com"));
+}
+
+#[test]
+fn test_local_part_length() {
+    let local = "a".repeat(64);
+    assert!(vale::is_email(&format!("{}@example.com", local)));
+    assert!(!vale::is_email(&format!("{}a@example.com", local)));
+}
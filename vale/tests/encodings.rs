@@ -0,0 +1,50 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Hex {
+    #[validate(hex)]
+    hash: String,
+}
+
+#[test]
+fn test_valid_hex() {
+    for hash in ["", "00", "deadBEEF", "0123456789abcdef"] {
+        Hex { hash: hash.to_string() }.validate().unwrap();
+    }
+}
+
+#[test]
+fn test_invalid_hex() {
+    for hash in ["abc", "0x00", "cheese", "12 34"] {
+        assert_eq!(
+            Hex { hash: hash.to_string() }.validate(),
+            Err(vec!["Failed to validate field `hash`, not a valid hexadecimal string".to_string()]),
+        );
+    }
+}
+
+#[cfg(feature = "base64")]
+#[derive(Validate)]
+struct Base64 {
+    #[validate(base64)]
+    token: String,
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_valid_base64() {
+    for token in ["", "Y2hlZXNl", "Y2hlZXNlIQ==", "+/+/"] {
+        Base64 { token: token.to_string() }.validate().unwrap();
+    }
+}
+
+#[cfg(feature = "base64")]
+#[test]
+fn test_invalid_base64() {
+    for token in ["Y2hlZXNl!", "Y2hlZXNlIQ", "Y2hl ZXNl", "-_-_"] {
+        assert_eq!(
+            Base64 { token: token.to_string() }.validate(),
+            Err(vec!["Failed to validate field `token`, not a valid base64 string".to_string()]),
+        );
+    }
+}
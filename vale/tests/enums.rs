@@ -0,0 +1,46 @@
+use vale::Validate;
+
+#[derive(Validate)]
+enum Shape {
+    Circle {
+        #[validate(gt(0.0))]
+        radius: f64,
+    },
+    Rectangle {
+        #[validate(gt(0))]
+        width: u32,
+        #[validate(gt(0))]
+        height: u32,
+    },
+    Polygon(#[validate(len_gt(2))] Vec<(i32, i32)>),
+    Empty,
+}
+
+#[test]
+fn test_valid() {
+    Shape::Circle { radius: 1.0 }.validate().unwrap();
+    Shape::Rectangle { width: 1, height: 2 }.validate().unwrap();
+    Shape::Polygon(vec![(0, 0), (1, 0), (0, 1)]).validate().unwrap();
+    Shape::Empty.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `radius`, value too low\"]")]
+fn test_named_variant() {
+    Shape::Circle { radius: 0.0 }.validate().unwrap();
+}
+
+#[test]
+fn test_multiple_fields() {
+    let mut shape = Shape::Rectangle { width: 0, height: 3 };
+    assert_eq!(
+        shape.validate(),
+        Err(vec!["Failed to validate field `width`, value too low".to_string()]),
+    );
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `0`, value too short\"]")]
+fn test_tuple_variant() {
+    Shape::Polygon(vec![(0, 0)]).validate().unwrap();
+}
@@ -0,0 +1,30 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(eq_ignore_case("US"))]
+    country: String,
+    #[validate(each(eq_ignore_case("ok")))]
+    statuses: Vec<String>,
+}
+
+#[test]
+fn matches_any_case() {
+    let mut s = Address { country: "us".to_string(), statuses: vec!["OK".to_string(), "Ok".to_string()] };
+    s.validate().unwrap();
+    s.country = "US".to_string();
+    s.validate().unwrap();
+    assert_eq!(s.country, "US");
+}
+
+#[test]
+fn different_value() {
+    let mut s = Address { country: "NL".to_string(), statuses: vec!["okay".to_string()] };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `country`, value is not equal to \"US\", ignoring case".to_string(),
+            "Failed to validate field `statuses[0]`, value is not equal to \"ok\", ignoring case".to_string(),
+        ]),
+    );
+}
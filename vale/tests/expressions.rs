@@ -0,0 +1,81 @@
+use vale::Validate;
+
+const MAX_RETRIES: u32 = 3;
+
+mod limits {
+    pub const DEFAULT: &str = "standard";
+    pub const MIN_AGE: u32 = 18;
+
+    pub fn max_score() -> u32 {
+        50 + 50
+    }
+}
+
+fn allowed_prefix() -> String {
+    "urn:".to_string()
+}
+
+#[derive(Validate)]
+struct Settings {
+    #[validate(eq(MAX_RETRIES))]
+    retries: u32,
+    #[validate(eq(limits::DEFAULT))]
+    plan: &'static str,
+    #[validate(eq(limits::max_score() - 1))]
+    score: u32,
+    #[validate(gte(limits::MIN_AGE), lte(limits::max_score()))]
+    age: u32,
+    #[validate(range(-(MAX_RETRIES as i32), MAX_RETRIES as i32 * 2))]
+    offset: i32,
+    #[validate(starts_with(&*allowed_prefix()))]
+    id: String,
+}
+
+fn valid_settings() -> Settings {
+    Settings {
+        retries: 3,
+        plan: "standard",
+        score: 99,
+        age: 30,
+        offset: -2,
+        id: "urn:42".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    valid_settings().validate().unwrap();
+}
+
+#[test]
+fn test_consts_and_function_calls() {
+    let mut s = Settings {
+        retries: 4,
+        plan: "premium",
+        score: 100,
+        age: 101,
+        offset: 7,
+        id: "42".to_string(),
+    };
+    assert_eq!(
+        s.validate().unwrap_err(),
+        [
+            "Failed to validate field `retries`, value incorrect",
+            "Failed to validate field `plan`, value incorrect",
+            "Failed to validate field `score`, value incorrect",
+            "Failed to validate field `age`, value must be at most limits::max_score()",
+            "Failed to validate field `offset`, value out of range [-(MAX_RETRIES as i32), MAX_RETRIES as i32 * 2]",
+            "Failed to validate field `id`, value does not start with &*allowed_prefix()",
+        ],
+    );
+}
+
+#[test]
+fn test_path_in_description() {
+    let mut s = valid_settings();
+    s.age = 17;
+    assert_eq!(
+        s.validate().unwrap_err(),
+        ["Failed to validate field `age`, value must be at least limits::MIN_AGE"],
+    );
+}
@@ -0,0 +1,56 @@
+use vale::Validate;
+
+struct Struct {
+    value: u32,
+    calls: u32,
+}
+
+impl Struct {
+    fn count(&mut self) -> bool {
+        self.calls += 1;
+        true
+    }
+}
+
+struct FailFast(Struct);
+
+impl vale::Validate for FailFast {
+    #[vale::ruleset(fail_fast)]
+    fn validate(&mut self) -> vale::Result {
+        vale::rule!(self.0.value > 10, "Too low");
+        vale::rule!(self.0.value < 5, "Too high");
+        vale::rule!(self.0.count());
+    }
+}
+
+struct CollectAll(Struct);
+
+impl vale::Validate for CollectAll {
+    #[vale::ruleset]
+    fn validate(&mut self) -> vale::Result {
+        vale::rule!(self.0.value > 10, "Too low");
+        vale::rule!(self.0.value < 5, "Too high");
+        vale::rule!(self.0.count());
+    }
+}
+
+#[test]
+fn test_fail_fast() {
+    let mut s = FailFast(Struct { value: 7, calls: 0 });
+    assert_eq!(s.validate(), Err(vec!["Too low".to_string()]));
+    assert_eq!(s.0.calls, 0);
+}
+
+#[test]
+fn test_fail_fast_second_rule() {
+    let mut s = FailFast(Struct { value: 11, calls: 0 });
+    assert_eq!(s.validate(), Err(vec!["Too high".to_string()]));
+    assert_eq!(s.0.calls, 0);
+}
+
+#[test]
+fn test_collect_all() {
+    let mut s = CollectAll(Struct { value: 7, calls: 0 });
+    assert_eq!(s.validate(), Err(vec!["Too low".to_string(), "Too high".to_string()]));
+    assert_eq!(s.0.calls, 1);
+}
@@ -0,0 +1,103 @@
+use vale::{FieldErrors, Validate};
+
+#[derive(Validate)]
+#[validate(with(names_differ))]
+struct Struct {
+    #[validate(gt(0), lt(10))]
+    id: i32,
+    #[validate(trim, len_gt(2), len_lt(10))]
+    name: String,
+    #[validate(len_gt(2))]
+    nickname: String,
+    unvalidated: bool,
+}
+
+fn names_differ(s: &mut Struct) -> bool {
+    s.name != s.nickname
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        id: 1,
+        name: "Luuk".to_string(),
+        nickname: "ThouCheese".to_string(),
+        unvalidated: true,
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate_fields().unwrap();
+    assert!(s.unvalidated);
+}
+
+#[test]
+fn test_grouped() {
+    let mut s = valid_struct();
+    s.id = 0;
+    s.name = " a ".to_string();
+    s.nickname = "a".to_string();
+    let errors = s.validate_fields().unwrap_err();
+    assert_eq!(errors.get("id"), Some(&["Failed to validate field `id`, value too low".to_string()][..]));
+    assert_eq!(errors.get("name"), Some(&["Failed to validate field `name`, value too short".to_string()][..]));
+    assert_eq!(errors.get(""), Some(&["Failed to validate `Struct`, value did not pass test".to_string()][..]));
+    assert_eq!(errors.get("unvalidated"), None);
+    assert_eq!(errors.iter().count(), 4);
+}
+
+#[test]
+fn test_flatten() {
+    let mut errors = FieldErrors::new();
+    errors.extend("b", vec!["b1".to_string(), "b2".to_string()]);
+    errors.extend("a", vec!["a1".to_string()]);
+    errors.extend("c", Vec::new());
+    assert_eq!(Vec::from(errors), vec!["b1", "b2", "a1"]);
+}
+
+#[derive(Validate)]
+struct Unsorted {
+    #[validate(len_gt(0))]
+    zip_code: String,
+    #[validate(gt(0))]
+    age: u32,
+    #[validate(len_gt(0))]
+    name: String,
+}
+
+#[test]
+fn test_declaration_order() {
+    let mut s = Unsorted { zip_code: String::new(), age: 0, name: String::new() };
+    let errors = s.validate_fields().unwrap_err();
+    let fields: Vec<&str> = errors.iter().map(|(field, _)| field).collect();
+    assert_eq!(fields, ["zip_code", "age", "name"]);
+    assert_eq!(Vec::from(errors), s.validate().unwrap_err());
+}
+
+#[test]
+fn test_default_implementation() {
+    struct Manual;
+
+    impl Validate for Manual {
+        #[vale::ruleset]
+        fn validate(&mut self) -> vale::Result {
+            vale::rule!(false, "always fails");
+        }
+    }
+
+    let errors = Manual.validate_fields().unwrap_err();
+    assert_eq!(errors.get(""), Some(&["always fails".to_string()][..]));
+}
+
+#[test]
+#[cfg(feature = "serde")]
+fn test_serialize() {
+    let mut s = valid_struct();
+    s.id = 12;
+    s.nickname = "a".to_string();
+    let errors = s.validate_fields().unwrap_err();
+    assert_eq!(
+        serde_json::to_string(&errors).unwrap(),
+        r#"{"id":["Failed to validate field `id`, value too high"],"nickname":["Failed to validate field `nickname`, value too short"]}"#,
+    );
+}
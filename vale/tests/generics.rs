@@ -0,0 +1,42 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Wrapper<T: PartialOrd<i32>> {
+    #[validate(gt(0))]
+    x: T,
+}
+
+#[derive(Validate)]
+struct Page<'a, T>
+where
+    T: Validate,
+{
+    #[validate(len_gt(0))]
+    title: &'a str,
+    #[validate(nested)]
+    items: Vec<T>,
+}
+
+#[derive(Validate)]
+struct Item {
+    #[validate(lt(10))]
+    value: u8,
+}
+
+#[test]
+fn test_generic_field() {
+    let mut w = Wrapper { x: 3 };
+    w.validate().unwrap();
+    assert!(Wrapper { x: 3 }.validate_ref().is_ok());
+    let mut w = Wrapper { x: -1 };
+    assert_eq!(w.validate(), Err(vec!["Failed to validate field `x`, value too low".to_string()]));
+}
+
+#[test]
+fn test_lifetime_and_where_clause() {
+    let mut page = Page { title: "items", items: vec![Item { value: 1 }, Item { value: 12 }] };
+    assert_eq!(
+        page.validate(),
+        Err(vec!["items: [1]: Failed to validate field `value`, value too high".to_string()]),
+    );
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "graphemes")]
+
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(grapheme_len_lt(3))]
+    emoji: String,
+    #[validate(grapheme_len_range(1, 2))]
+    range: String,
+    #[validate(len_eq(1, count = "graphemes"))]
+    accented: String,
+}
+
+#[test]
+fn test_family_is_one_grapheme() {
+    let family = "👨‍👩‍👧";
+    assert_eq!(family.chars().count(), 5);
+    let mut s = Struct {
+        emoji: format!("{}{}", family, family),
+        range: family.to_string(),
+        accented: "e\u{301}".to_string(),
+    };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_too_many_graphemes() {
+    let mut s = Struct {
+        emoji: "abc".to_string(),
+        range: "👨‍👩‍👧🧀🧀".to_string(),
+        accented: "ee\u{301}".to_string(),
+    };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `emoji`, value too long".to_string(),
+            "Failed to validate field `range`, length out of range".to_string(),
+            "Failed to validate field `accented`, value of incorrect length".to_string(),
+        ]),
+    );
+}
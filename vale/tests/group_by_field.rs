@@ -0,0 +1,72 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(len_gt(0))]
+    street: String,
+}
+
+#[derive(Validate)]
+#[validate(with(|s: &mut Struct| s.name != s.email))]
+struct Struct {
+    #[validate(trim, len_lt(8), len_gt(3))]
+    name: String,
+    #[validate(email)]
+    email: String,
+    #[validate(each(len_lt(5)))]
+    tags: Vec<String>,
+    #[validate(nested)]
+    address: Address,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        name: "Luuk".to_string(),
+        email: "luuk@example.com".to_string(),
+        tags: vec!["rust".to_string()],
+        address: Address { street: "Kaasstraat".to_string() },
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_two_failing_fields() {
+    let mut s = valid_struct();
+    s.name = "A name that is too long".to_string();
+    s.email = "not an email".to_string();
+    let grouped = vale::group_by_field(s.validate().unwrap_err());
+    assert_eq!(grouped.len(), 2);
+    assert_eq!(grouped["name"], ["Failed to validate field `name`, value too long"]);
+    assert_eq!(grouped["email"], ["Failed to validate field `email`, not a valid email address"]);
+}
+
+#[test]
+fn test_elements_nested_and_container() {
+    let mut s = valid_struct();
+    s.name = "Al".to_string();
+    s.email = "Al".to_string();
+    s.tags = vec!["cheese".to_string(), "rust".to_string(), "wensleydale".to_string()];
+    s.address.street = String::new();
+    let grouped = vale::group_by_field(s.validate().unwrap_err());
+    assert_eq!(grouped.len(), 4);
+    assert_eq!(grouped["name"], ["Failed to validate field `name`, value too short"]);
+    assert_eq!(
+        grouped["tags"],
+        [
+            "Failed to validate field `tags[0]`, value too long",
+            "Failed to validate field `tags[2]`, value too long",
+        ],
+    );
+    assert_eq!(
+        grouped[""],
+        [
+            "address: Failed to validate field `street`, value too short",
+            "Failed to validate `Struct`, value did not pass test",
+        ],
+    );
+}
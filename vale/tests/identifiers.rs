@@ -0,0 +1,47 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Post {
+    #[validate(slug)]
+    slug: String,
+    #[validate(ascii_alphanumeric)]
+    key: String,
+}
+
+fn post(slug: &str, key: &str) -> Post {
+    Post { slug: slug.to_string(), key: key.to_string() }
+}
+
+const SLUG_ERROR: &str = "Failed to validate field `slug`, not a valid slug, which consists of lowercase letters and digits separated by single hyphens";
+
+#[test]
+fn test_valid() {
+    post("my-post-1", "Abc123").validate().unwrap();
+    post("post", "").validate().unwrap();
+}
+
+#[test]
+fn test_invalid_slug() {
+    for slug in ["My_Post", "-x", "x-", "a--b", "", "caf\u{e9}"] {
+        assert_eq!(post(slug, "key").validate().unwrap_err(), [SLUG_ERROR], "{:?}", slug);
+    }
+}
+
+#[test]
+fn test_invalid_ascii_alphanumeric() {
+    for key in ["my-key", "my key", "k\u{e9}y"] {
+        assert_eq!(
+            post("slug", key).validate().unwrap_err(),
+            ["Failed to validate field `key`, value contains characters other than ascii letters and digits"],
+        );
+    }
+}
+
+#[test]
+fn test_is_slug() {
+    assert!(vale::is_slug("my-post-1"));
+    assert!(vale::is_slug("2024"));
+    assert!(!vale::is_slug("My_Post"));
+    assert!(!vale::is_slug("-x"));
+    assert!(!vale::is_slug("a--b"));
+}
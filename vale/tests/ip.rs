@@ -0,0 +1,38 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(ipv4)]
+    v4: String,
+    #[validate(ipv6)]
+    v6: String,
+    #[validate(ip)]
+    any: String,
+}
+
+fn with(v4: &str, v6: &str, any: &str) -> Struct {
+    Struct { v4: v4.to_string(), v6: v6.to_string(), any: any.to_string() }
+}
+
+#[test]
+fn test_valid() {
+    with("127.0.0.1", "::1", "10.0.0.1").validate().unwrap();
+    with("255.255.255.255", "2001:db8::ff00:42:8329", "fe80::1").validate().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    assert_eq!(
+        with("256.0.0.1", "2001:db8::g", "localhost").validate(),
+        Err(vec![
+            "Failed to validate field `v4`, not a valid IPv4 address".to_string(),
+            "Failed to validate field `v6`, not a valid IPv6 address".to_string(),
+            "Failed to validate field `any`, not a valid IPv4 or IPv6 address".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_wrong_kind() {
+    assert_eq!(with("::1", "127.0.0.1", "::1").validate().unwrap_err().len(), 2);
+}
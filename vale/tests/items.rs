@@ -0,0 +1,73 @@
+use std::collections::HashSet;
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(min_items(1), max_items(3))]
+    tags: Vec<String>,
+    #[validate(unique_items)]
+    ids: Vec<i32>,
+    #[validate(min_items(1), unique_items)]
+    set: HashSet<i32>,
+}
+
+#[derive(Validate)]
+struct Single {
+    #[validate(max_items(1))]
+    values: Vec<i32>,
+}
+
+fn with_tags(tags: &[&str]) -> Struct {
+    Struct {
+        tags: tags.iter().map(|t| t.to_string()).collect(),
+        ids: vec![1, 2, 3],
+        set: vec![1].into_iter().collect(),
+    }
+}
+
+#[test]
+fn test_at_limits() {
+    with_tags(&["a"]).validate().unwrap();
+    with_tags(&["a", "b", "c"]).validate().unwrap();
+}
+
+#[test]
+fn test_empty() {
+    let mut s = with_tags(&[]);
+    s.ids.clear();
+    s.set.clear();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `tags`, must contain at least 1 item".to_string(),
+            "Failed to validate field `set`, must contain at least 1 item".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_too_many() {
+    assert_eq!(
+        with_tags(&["a", "b", "c", "d"]).validate(),
+        Err(vec!["Failed to validate field `tags`, must contain at most 3 items".to_string()]),
+    );
+}
+
+#[test]
+fn test_duplicates() {
+    let mut s = with_tags(&["a"]);
+    s.ids = vec![1, 2, 1];
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `ids`, contains duplicate items".to_string()]),
+    );
+}
+
+#[test]
+fn test_single_item() {
+    Single { values: vec![1] }.validate().unwrap();
+    assert_eq!(
+        Single { values: vec![1, 2] }.validate(),
+        Err(vec!["Failed to validate field `values`, must contain at most 1 item".to_string()]),
+    );
+}
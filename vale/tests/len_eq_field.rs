@@ -0,0 +1,28 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Packet {
+    values: Vec<u8>,
+    #[validate(len_eq_field(values))]
+    parity: Vec<bool>,
+}
+
+fn packet(values: &[u8], parity: &[bool]) -> Packet {
+    Packet { values: values.to_vec(), parity: parity.to_vec() }
+}
+
+#[test]
+fn test_equal() {
+    packet(&[1, 2, 3], &[true, false, true]).validate().unwrap();
+    packet(&[1, 2, 3], &[true, false, true]).validate_ref().unwrap();
+    packet(&[], &[]).validate().unwrap();
+}
+
+#[test]
+fn test_unequal() {
+    let expected = Err(vec![
+        "Failed to validate field `parity`, length does not match that of `values`".to_string(),
+    ]);
+    assert_eq!(packet(&[1, 2, 3], &[true]).validate(), expected);
+    assert_eq!(packet(&[1], &[true, false]).validate_ref(), expected);
+}
@@ -0,0 +1,35 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(len_range(3, 5))]
+    name: String,
+    #[validate(len_range(1, 2))]
+    items: Vec<i32>,
+}
+
+fn with_name(name: &str) -> Struct {
+    Struct { name: name.to_string(), items: vec![1] }
+}
+
+#[test]
+fn test_bounds() {
+    with_name("abc").validate().unwrap();
+    with_name("abcd").validate().unwrap();
+    with_name("abcde").validate().unwrap();
+}
+
+#[test]
+fn test_outside_bounds() {
+    let expected = Err(vec!["Failed to validate field `name`, length out of range".to_string()]);
+    assert_eq!(with_name("ab").validate(), expected);
+    assert_eq!(with_name("abcdef").validate(), expected);
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `items`, length out of range\"]")]
+fn test_empty_collection() {
+    let mut s = with_name("abc");
+    s.items.clear();
+    s.validate().unwrap();
+}
@@ -0,0 +1,75 @@
+#![deny(warnings, clippy::all, clippy::pedantic, clippy::nursery)]
+
+use vale::Validate;
+
+#[derive(Validate)]
+#[validate(show_values)]
+struct Struct {
+    #[validate(gt(0), lt(100), eq(3), neq(4), lte(5), gte(1), positive, non_zero)]
+    number: i32,
+    #[validate(divisible_by(1), range(0, 10), range_exclusive(-1, 11), one_of(1, 2, 3), not_one_of(4))]
+    other: i32,
+    #[validate(trim, to_lower_case, to_upper_case, capitalize, truncate(5), default_if_empty("abc"))]
+    #[validate(len_lt(10), len_eq(3), len_gt(0), len_neq(4), len_range(1, 5), len_lt(10, count = "chars"))]
+    #[validate(contains("A"), starts_with("A"), ends_with("C"), alphanumeric, ascii)]
+    string: String,
+    #[validate(email)]
+    email: String,
+    #[validate(required, with(check), try_with(try_check))]
+    optional: Option<i32>,
+    #[validate(min_items(1), max_items(3), unique_items, each(gt(0)))]
+    items: Vec<i32>,
+    #[validate(nested)]
+    nested: Inner,
+    #[validate(ip, when = "self.number > 0", rename = "IP")]
+    ip: String,
+    #[validate(matches_field(email))]
+    email_confirm: String,
+}
+
+#[derive(Validate)]
+struct Inner {
+    #[validate(numeric(message = "not numeric"))]
+    digits: String,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+const fn check(_: &mut i32) -> bool {
+    true
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref, clippy::unnecessary_wraps)]
+const fn try_check(_: &mut i32) -> Result<(), String> {
+    Ok(())
+}
+
+struct Manual {
+    value: u32,
+}
+
+impl Validate for Manual {
+    #[vale::ruleset(fail_fast)]
+    fn validate(&mut self) -> vale::Result {
+        vale::rule!(self.value > 3);
+        vale::rule!(self.value < 10, "too high");
+        vale::rule!(self.value != 5, "value is {}", self.value);
+    }
+}
+
+#[test]
+fn test_strict_lints() {
+    let mut s = Struct {
+        number: 3,
+        other: 2,
+        string: " abc ".to_string(),
+        email: "a@b.c".to_string(),
+        optional: Some(1),
+        items: vec![1],
+        nested: Inner { digits: "12".to_string() },
+        ip: "::1".to_string(),
+        email_confirm: "a@b.c".to_string(),
+    };
+    s.validate().unwrap();
+    s.validate_fields().unwrap();
+    Manual { value: 4 }.validate().unwrap();
+}
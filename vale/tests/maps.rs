@@ -0,0 +1,76 @@
+use std::collections::{BTreeMap, HashMap};
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Settings {
+    #[validate(each_value(gt(0)))]
+    limits: BTreeMap<String, i32>,
+    #[validate(each_key(len_lt(8), ascii), each_value(trim, len_gt(0)))]
+    labels: HashMap<String, String>,
+    #[validate(each_value(each(lt(10))))]
+    scores: BTreeMap<u8, Vec<u8>>,
+}
+
+#[derive(Validate)]
+struct Readonly {
+    #[validate(each_key(gt(0)), each_value(len_gt(0)))]
+    names: BTreeMap<u32, String>,
+}
+
+fn valid_settings() -> Settings {
+    Settings {
+        limits: vec![("timeout".to_string(), 30), ("retries".to_string(), 3)].into_iter().collect(),
+        labels: vec![("env".to_string(), " prod ".to_string())].into_iter().collect(),
+        scores: vec![(1, vec![1, 2])].into_iter().collect(),
+    }
+}
+
+#[test]
+fn valid() {
+    let mut s = valid_settings();
+    s.validate().unwrap();
+    assert_eq!(s.labels["env"], "prod");
+}
+
+#[test]
+fn invalid_value() {
+    let mut s = valid_settings();
+    s.limits.insert("timeout".to_string(), 0);
+    s.limits.insert("delay".to_string(), -1);
+    s.scores.insert(2, vec![3, 12]);
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `limits[\"delay\"]`, value too low".to_string(),
+            "Failed to validate field `limits[\"timeout\"]`, value too low".to_string(),
+            "Failed to validate field `scores[2][1]`, value too high".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn invalid_key() {
+    let mut s = valid_settings();
+    s.labels = vec![("environment".to_string(), "  ".to_string())].into_iter().collect();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `labels[\"environment\"]`, value too long".to_string(),
+            "Failed to validate field `labels[\"environment\"]`, value too short".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn validate_ref() {
+    let s = Readonly { names: vec![(1, "Luuk".to_string())].into_iter().collect() };
+    s.validate_ref().unwrap();
+    let s = Readonly { names: vec![(0, String::new())].into_iter().collect() };
+    assert_eq!(
+        s.validate_ref(),
+        Err(vec![
+            "Failed to validate field `names[0]`, value too low".to_string(),
+            "Failed to validate field `names[0]`, value too short".to_string(),
+        ]),
+    );
+}
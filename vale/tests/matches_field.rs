@@ -0,0 +1,31 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Signup {
+    password: String,
+    #[validate(matches_field(password))]
+    password_confirm: String,
+}
+
+fn signup(password: &str, password_confirm: &str) -> Signup {
+    Signup {
+        password: password.to_string(),
+        password_confirm: password_confirm.to_string(),
+    }
+}
+
+#[test]
+fn test_matching() {
+    signup("hunter2", "hunter2").validate().unwrap();
+    signup("hunter2", "hunter2").validate_ref().unwrap();
+}
+
+#[test]
+fn test_not_matching() {
+    let expected = Err(vec![
+        "Failed to validate field `password_confirm`, value does not match `password`".to_string(),
+    ]);
+    assert_eq!(signup("hunter2", "hunter3").validate(), expected);
+    assert_eq!(signup("hunter2", "hunter3").validate_ref(), expected);
+}
+
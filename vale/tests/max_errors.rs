@@ -0,0 +1,70 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+#[validate(max_errors = 10)]
+struct Batch {
+    #[validate(gt(0))]
+    id: i32,
+    #[validate(each(lt(100)))]
+    values: Vec<u32>,
+    #[validate(len_gt(0))]
+    name: String,
+}
+
+#[derive(Validate)]
+struct Unlimited {
+    #[validate(each(lt(100)))]
+    values: Vec<u32>,
+}
+
+struct Rules {
+    values: Vec<u32>,
+}
+
+impl Validate for Rules {
+    #[vale::ruleset(max_errors = 3)]
+    fn validate(&mut self) -> vale::Result {
+        for value in &self.values {
+            vale::rule!(*value < 100, "{} is too high", value);
+        }
+        vale::rule!(self.values.len() < 5, "Too many values");
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut batch = Batch { id: 1, values: vec![1, 2, 3], name: "batch".to_string() };
+    batch.validate().unwrap();
+    batch.validate_ref().unwrap();
+}
+
+#[test]
+fn test_capped() {
+    // one error for `id`, 18 for `values` and one for `name`
+    let mut batch = Batch { id: 0, values: vec![100; 18], name: String::new() };
+    let errors = batch.validate().unwrap_err();
+    assert_eq!(errors.len(), 10);
+    assert_eq!(errors[0], "Failed to validate field `id`, value too low");
+    assert_eq!(errors[9], "Failed to validate field `values[8]`, value too high");
+    assert_eq!(batch.validate_ref().unwrap_err(), errors);
+}
+
+#[test]
+fn test_below_cap() {
+    let mut batch = Batch { id: 0, values: vec![100; 3], name: String::new() };
+    assert_eq!(batch.validate().unwrap_err().len(), 5);
+}
+
+#[test]
+fn test_unlimited() {
+    let mut unlimited = Unlimited { values: vec![100; 20] };
+    assert_eq!(unlimited.validate().unwrap_err().len(), 20);
+}
+
+#[test]
+fn test_ruleset() {
+    let mut rules = Rules { values: vec![100; 20] };
+    assert_eq!(rules.validate().unwrap_err(), ["100 is too high"; 3]);
+    let mut rules = Rules { values: vec![100] };
+    assert_eq!(rules.validate().unwrap_err(), ["100 is too high"]);
+}
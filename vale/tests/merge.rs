@@ -0,0 +1,35 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(len_gt(0))]
+    street: String,
+}
+
+#[derive(Validate)]
+struct Contact {
+    #[validate(email)]
+    email: String,
+}
+
+#[test]
+fn merge_one_failure() {
+    let mut billing = Address { street: "Dam 1".to_string() };
+    let mut shipping = Address { street: String::new() };
+    let mut contact = Contact { email: "luuk@example.com".to_string() };
+    assert_eq!(
+        vale::merge(vec![billing.validate(), shipping.validate(), contact.validate()]),
+        Err(vec!["Failed to validate field `street`, value too short".to_string()]),
+    );
+}
+
+#[test]
+fn merge_all_failures() {
+    let results = vec![Err(vec!["a".to_string()]), Ok(()), Err(vec!["b".to_string(), "c".to_string()])];
+    assert_eq!(vale::merge(results), Err(vec!["a".to_string(), "b".to_string(), "c".to_string()]));
+}
+
+#[test]
+fn merge_empty() {
+    assert_eq!(vale::merge(Vec::new()), Ok(()));
+}
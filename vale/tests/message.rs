@@ -0,0 +1,83 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(gt(0, message = "id must be positive"))]
+    id: i32,
+    #[validate(len_gt(3, message = "{} is too short"))]
+    name: String,
+    #[validate(email(message = "that is not an email address"))]
+    email: String,
+    #[validate(len_lt(10))]
+    nickname: String,
+    #[validate(range(1, 10, message_fn = describe_level))]
+    level: u8,
+    #[validate(each(len_gt(0, message_fn = messages::describe_tag)))]
+    tags: Vec<String>,
+}
+
+fn describe_level(level: &u8) -> String {
+    format!("level {} is out of range", level)
+}
+
+mod messages {
+    pub fn describe_tag(tag: &String) -> String {
+        format!("tag `{}` is empty", tag)
+    }
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        id: 1,
+        name: "ThouCheese".to_string(),
+        email: "luuk@example.com".to_string(),
+        nickname: "cheese".to_string(),
+        level: 3,
+        tags: vec!["rust".to_string()],
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_custom_message() {
+    let mut s = valid_struct();
+    s.id = 0;
+    s.email = "luuk".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["id must be positive".to_string(), "that is not an email address".to_string()]),
+    );
+}
+
+#[test]
+fn test_placeholder_left_as_is() {
+    let mut s = valid_struct();
+    s.name = "TC".to_string();
+    assert_eq!(s.validate(), Err(vec!["{} is too short".to_string()]));
+}
+
+#[test]
+fn test_default_message() {
+    let mut s = valid_struct();
+    s.nickname = "the big cheese".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `nickname`, value too long".to_string()]),
+    );
+}
+
+#[test]
+fn test_message_fn() {
+    let mut s = valid_struct();
+    s.level = 42;
+    s.tags.push(String::new());
+    assert_eq!(
+        s.validate(),
+        Err(vec!["level 42 is out of range".to_string(), "tag `` is empty".to_string()]),
+    );
+}
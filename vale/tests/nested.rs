@@ -0,0 +1,58 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(len_eq(6))]
+    zip: String,
+    #[validate(trim)]
+    city: String,
+}
+
+#[derive(Validate)]
+struct Person {
+    #[validate(len_gt(0))]
+    name: String,
+    #[validate(nested)]
+    address: Address,
+    #[validate(nested)]
+    billing: Option<Address>,
+}
+
+fn valid_person() -> Person {
+    Person {
+        name: "Luuk".to_string(),
+        address: Address { zip: "1234AB".to_string(), city: " Delft ".to_string() },
+        billing: None,
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut p = valid_person();
+    p.validate().unwrap();
+    assert_eq!(p.address.city, "Delft");
+}
+
+#[test]
+fn test_inner_invalid() {
+    let mut p = valid_person();
+    p.address.zip = "1234".to_string();
+    assert_eq!(
+        p.validate(),
+        Err(vec!["address: Failed to validate field `zip`, value of incorrect length".to_string()]),
+    );
+}
+
+#[test]
+fn test_optional_inner_invalid() {
+    let mut p = valid_person();
+    p.name = String::new();
+    p.billing = Some(Address { zip: "1".to_string(), city: "Delft".to_string() });
+    assert_eq!(
+        p.validate(),
+        Err(vec![
+            "Failed to validate field `name`, value too short".to_string(),
+            "billing: Failed to validate field `zip`, value of incorrect length".to_string(),
+        ]),
+    );
+}
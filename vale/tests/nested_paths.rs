@@ -0,0 +1,105 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(len_gt(0))]
+    street: String,
+}
+
+#[derive(Validate)]
+#[validate(with(|c: &mut Customer| c.email != c.name))]
+struct Customer {
+    #[validate(len_gt(0))]
+    name: String,
+    #[validate(email)]
+    email: String,
+    #[validate(nested)]
+    address: Address,
+}
+
+#[derive(Validate)]
+struct Order {
+    #[validate(gt(0))]
+    amount: u32,
+    #[validate(nested)]
+    customer: Customer,
+    #[validate(nested)]
+    billing: Option<Address>,
+}
+
+#[derive(Validate)]
+struct Request {
+    #[validate(nested)]
+    order: Order,
+}
+
+fn valid_request() -> Request {
+    Request {
+        order: Order {
+            amount: 3,
+            customer: Customer {
+                name: "Luuk".to_string(),
+                email: "luuk@example.com".to_string(),
+                address: Address { street: "Kaasstraat".to_string() },
+            },
+            billing: None,
+        },
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut request = valid_request();
+    request.validate().unwrap();
+    request.validate_fields().unwrap();
+}
+
+#[test]
+fn test_three_levels() {
+    let mut request = valid_request();
+    request.order.customer.email = "luuk".to_string();
+    request.order.customer.address.street = String::new();
+    let errors = request.validate_fields().unwrap_err();
+    let paths: Vec<&str> = errors.iter().map(|(path, _)| path).collect();
+    assert_eq!(paths, ["order.customer.email", "order.customer.address.street"]);
+    assert_eq!(
+        errors.get("order.customer.email").unwrap(),
+        ["order: customer: Failed to validate field `email`, not a valid email address"],
+    );
+    assert_eq!(
+        errors.get("order.customer.address.street").unwrap(),
+        ["order: customer: address: Failed to validate field `street`, value too short"],
+    );
+}
+
+#[test]
+fn test_flattened_errors_match_validate() {
+    let mut request = valid_request();
+    request.order.amount = 0;
+    request.order.customer.name = String::new();
+    request.order.customer.email = String::new();
+    request.order.billing = Some(Address { street: String::new() });
+    let errors = request.validate_fields().unwrap_err();
+    let paths: Vec<&str> = errors.iter().map(|(path, _)| path).collect();
+    assert_eq!(
+        paths,
+        [
+            "order.amount",
+            "order.customer.name",
+            "order.customer.email",
+            "order.customer",
+            "order.billing.street",
+        ],
+    );
+    assert_eq!(Vec::<String>::from(errors), request.validate().unwrap_err());
+}
+
+#[test]
+fn test_validate_nested() {
+    let mut address = Address { street: String::new() };
+    let errors = address.validate_nested("billing").unwrap_err();
+    assert_eq!(
+        errors.get("billing.street").unwrap(),
+        ["Failed to validate field `street`, value too short"],
+    );
+}
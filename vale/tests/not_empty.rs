@@ -0,0 +1,56 @@
+use std::collections::HashMap;
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(not_empty)]
+    name: String,
+    #[validate(not_empty)]
+    tags: Vec<String>,
+    #[validate(not_empty)]
+    scores: HashMap<String, u32>,
+    #[validate(trim, not_empty)]
+    comment: Option<String>,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        name: "Luuk".to_string(),
+        tags: vec!["rust".to_string()],
+        scores: vec![("Luuk".to_string(), 3)].into_iter().collect(),
+        comment: None,
+    }
+}
+
+#[test]
+fn not_empty() {
+    valid_struct().validate().unwrap();
+}
+
+#[test]
+fn empty_string() {
+    let mut s = valid_struct();
+    s.name = String::new();
+    s.comment = Some("   ".to_string());
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `name`, value must not be empty".to_string(),
+            "Failed to validate field `comment`, value must not be empty".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn empty_collections() {
+    let mut s = valid_struct();
+    s.tags = Vec::new();
+    s.scores = HashMap::new();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `tags`, value must not be empty".to_string(),
+            "Failed to validate field `scores`, value must not be empty".to_string(),
+        ]),
+    );
+}
@@ -0,0 +1,51 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(one_of("red", "green", "blue"))]
+    color: String,
+    #[validate(not_one_of(0, 13))]
+    number: i32,
+    #[validate(one_of("only"))]
+    single: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        color: "green".to_string(),
+        number: 7,
+        single: "only".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `color`, value not in allowed set\"]")]
+fn test_not_allowed() {
+    let mut s = valid_struct();
+    s.color = "purple".to_string();
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `number`, value not allowed\"]")]
+fn test_denied() {
+    let mut s = valid_struct();
+    s.number = 13;
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_single_element() {
+    let mut s = valid_struct();
+    s.single = "other".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `single`, value not in allowed set".to_string()]),
+    );
+}
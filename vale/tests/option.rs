@@ -0,0 +1,56 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(gt(0))]
+    id: Option<i32>,
+    #[validate(required, len_gt(3))]
+    name: Option<String>,
+    #[validate(trim)]
+    nickname: Option<String>,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        id: Some(1),
+        name: Some("ThouCheese".to_string()),
+        nickname: None,
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `id`, value too low\"]")]
+fn test_some_invalid() {
+    let mut s = valid_struct();
+    s.id = Some(-1);
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_none_skipped() {
+    let mut s = valid_struct();
+    s.id = None;
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `name`, value is required\"]")]
+fn test_none_required() {
+    let mut s = valid_struct();
+    s.name = None;
+    s.validate().unwrap();
+}
+
+#[test]
+fn transform() {
+    let mut s = valid_struct();
+    s.nickname = Some("  cheese  ".to_string());
+    s.validate().unwrap();
+    assert_eq!(s.nickname.as_deref(), Some("cheese"));
+}
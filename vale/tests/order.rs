@@ -0,0 +1,48 @@
+use vale::Validate;
+
+#[derive(Validate)]
+#[validate(with(always_fails))]
+struct Struct {
+    #[validate(gt(0), lt(-10))]
+    first: i32,
+    #[validate(len_gt(5))]
+    #[validate(contains("@"), ends_with(".com"))]
+    second: String,
+    unvalidated: u8,
+    #[validate(each(gt(0), lt(-10)))]
+    third: Vec<i32>,
+    #[validate(required)]
+    fourth: Option<i32>,
+}
+
+fn always_fails(_: &mut Struct) -> bool {
+    false
+}
+
+#[test]
+fn declaration_order() {
+    let mut s = Struct {
+        first: 0,
+        second: "abc".to_string(),
+        unvalidated: 0,
+        third: vec![0, 0],
+        fourth: None,
+    };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `first`, value too low".to_string(),
+            "Failed to validate field `first`, value too high".to_string(),
+            "Failed to validate field `second`, value too short".to_string(),
+            "Failed to validate field `second`, value does not contain \"@\"".to_string(),
+            "Failed to validate field `second`, value does not end with \".com\"".to_string(),
+            "Failed to validate field `third[0]`, value too low".to_string(),
+            "Failed to validate field `third[0]`, value too high".to_string(),
+            "Failed to validate field `third[1]`, value too low".to_string(),
+            "Failed to validate field `third[1]`, value too high".to_string(),
+            "Failed to validate field `fourth`, value is required".to_string(),
+            "Failed to validate `Struct`, value did not pass test".to_string(),
+        ]),
+    );
+    assert_eq!(s.unvalidated, 0);
+}
@@ -0,0 +1,56 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Form {
+    #[validate(trim, parse_into(age))]
+    age_input: String,
+    #[validate(gt(0))]
+    age: u32,
+}
+
+#[test]
+fn parse_into() {
+    let mut s = Form { age_input: " 42 ".to_string(), age: 0 };
+    s.validate().unwrap();
+    assert_eq!(s.age, 42);
+}
+
+#[test]
+fn parse_into_invalid() {
+    let mut s = Form { age_input: "forty-two".to_string(), age: 1 };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `age_input`, value could not be parsed into `age`".to_string()]),
+    );
+    assert_eq!(s.age, 1);
+}
+
+#[test]
+fn parse_into_validates_target() {
+    let mut s = Form { age_input: "0".to_string(), age: 1 };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `age`, value too low".to_string()]),
+    );
+}
+
+fn parse_int(value: &mut String) -> Result<(), String> {
+    let parsed: i64 = value.parse().map_err(|_| format!("`{}` is not a number", value))?;
+    *value = parsed.to_string();
+    Ok(())
+}
+
+#[derive(Validate)]
+struct Normalized {
+    #[validate(try_with(parse_int))]
+    number: String,
+}
+
+#[test]
+fn try_with_conversion() {
+    let mut s = Normalized { number: "+42".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.number, "42");
+    s.number = "abc".to_string();
+    assert_eq!(s.validate(), Err(vec!["`abc` is not a number".to_string()]));
+}
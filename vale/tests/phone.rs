@@ -0,0 +1,43 @@
+#![cfg(feature = "phone")]
+
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(phone)]
+    phone: String,
+    #[validate(phone(format = "e164"))]
+    e164: String,
+}
+
+#[test]
+fn test_valid() {
+    let mut s = Struct { phone: "+14155552671".to_string(), e164: "+14155552671".to_string() };
+    s.validate().unwrap();
+    s.phone = "(020) 123-4567".to_string();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    let mut s = Struct { phone: "not a number".to_string(), e164: "0612345678".to_string() };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `phone`, not a valid phone number".to_string(),
+            "Failed to validate field `e164`, not a valid E.164 phone number".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_helpers() {
+    assert!(vale::is_phone("+31 20 123 4567"));
+    assert!(!vale::is_phone("123"));
+    assert!(!vale::is_phone("1234567890123456"));
+    assert!(!vale::is_phone("12+34567890"));
+    assert!(vale::is_e164("+31201234567"));
+    assert!(!vale::is_e164("+"));
+    assert!(!vale::is_e164("+031201234567"));
+    assert!(!vale::is_e164("+1234567890123456"));
+}
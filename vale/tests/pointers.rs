@@ -0,0 +1,81 @@
+#![allow(clippy::box_collection)]
+
+use std::rc::Rc;
+use std::sync::Arc;
+use vale::Validate;
+
+#[derive(Validate)]
+struct Inner {
+    #[validate(gt(0))]
+    id: i32,
+}
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(len_range(2, 5))]
+    boxed: Box<String>,
+    #[validate(gt(0), with(is_even))]
+    number: Box<i32>,
+    #[validate(nested)]
+    inner: Box<Inner>,
+    #[validate(len_lt(5), email)]
+    shared: Rc<String>,
+    #[validate(to_lower_case, eq("hi"))]
+    lowered: Arc<String>,
+    #[validate(lt(10))]
+    optional: Option<Box<u32>>,
+}
+
+fn is_even(num: &mut i32) -> bool {
+    *num % 2 == 0
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        boxed: Box::new("abc".to_string()),
+        number: Box::new(2),
+        inner: Box::new(Inner { id: 1 }),
+        shared: Rc::new("a@b".to_string()),
+        lowered: Arc::new("HI".to_string()),
+        optional: Some(Box::new(3)),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    assert_eq!(*s.lowered, "hi");
+}
+
+#[test]
+fn test_boxed_string() {
+    let mut s = valid_struct();
+    *s.boxed = "a".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `boxed`, length out of range".to_string()]),
+    );
+    *s.boxed = "abcdef".to_string();
+    assert_eq!(s.validate().unwrap_err().len(), 1);
+}
+
+#[test]
+fn test_invalid() {
+    let mut s = valid_struct();
+    *s.number = -1;
+    s.inner.id = 0;
+    s.shared = Rc::new("not an email".to_string());
+    s.optional = Some(Box::new(10));
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `number`, value too low".to_string(),
+            "Failed to validate field `number`, value did not pass test".to_string(),
+            "inner: Failed to validate field `id`, value too low".to_string(),
+            "Failed to validate field `shared`, value too long".to_string(),
+            "Failed to validate field `shared`, not a valid email address".to_string(),
+            "Failed to validate field `optional`, value too high".to_string(),
+        ]),
+    );
+}
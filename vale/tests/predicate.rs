@@ -0,0 +1,41 @@
+use vale::Validate;
+
+#[derive(Validate)]
+#[validate(predicate = "self.start < self.end")]
+struct Range {
+    start: u32,
+    end: u32,
+}
+
+#[derive(Validate)]
+#[validate(predicate("self.min <= self.max", message = "min exceeds max"))]
+struct Bounds {
+    min: i32,
+    max: i32,
+    #[validate(predicate = "self.min <= self.value && self.value <= self.max")]
+    value: i32,
+}
+
+#[test]
+fn test_valid() {
+    Range { start: 1, end: 2 }.validate().unwrap();
+    Bounds { min: 0, max: 10, value: 5 }.validate().unwrap();
+}
+
+#[test]
+fn test_container() {
+    let errors = Range { start: 2, end: 2 }.validate().unwrap_err();
+    assert_eq!(errors, ["Failed to validate `Range`, `self.start < self.end` does not hold"]);
+}
+
+#[test]
+fn test_field_and_message() {
+    let errors = Bounds { min: 10, max: 0, value: 5 }.validate().unwrap_err();
+    assert_eq!(
+        errors,
+        [
+            "Failed to validate field `value`, `self.min <= self.value && self.value <= self.max` does not hold",
+            "min exceeds max",
+        ],
+    );
+}
@@ -0,0 +1,78 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(range(1, 100))]
+    inclusive: i32,
+    #[validate(range_exclusive(1, 100))]
+    exclusive: i32,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        inclusive: 50,
+        exclusive: 50,
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_inclusive_boundaries() {
+    let mut s = valid_struct();
+    s.inclusive = 1;
+    s.validate().unwrap();
+    s.inclusive = 100;
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `inclusive`, value out of range [1, 100]\"]")]
+fn test_inclusive_too_low() {
+    let mut s = valid_struct();
+    s.inclusive = 0;
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `inclusive`, value out of range [1, 100]\"]")]
+fn test_inclusive_too_high() {
+    let mut s = valid_struct();
+    s.inclusive = 101;
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_exclusive_boundaries() {
+    let mut s = valid_struct();
+    s.exclusive = 1;
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `exclusive`, value out of range (1, 100)".to_string()]),
+    );
+    s.exclusive = 100;
+    assert!(s.validate().is_err());
+    s.exclusive = 2;
+    s.validate().unwrap();
+    s.exclusive = 99;
+    s.validate().unwrap();
+}
+
+#[derive(Validate)]
+struct Bounds {
+    #[validate(range(-5, 5))]
+    value: i32,
+}
+
+#[test]
+fn test_both_arguments_used() {
+    for value in -5..=5 {
+        Bounds { value }.validate().unwrap();
+    }
+    assert!(Bounds { value: -6 }.validate().is_err());
+    assert!(Bounds { value: 6 }.validate().is_err());
+}
@@ -0,0 +1,61 @@
+#![cfg(feature = "regex")]
+
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(regex("^[a-z0-9]+$"))]
+    username: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        username: "thoucheese42".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "[\"Failed to validate field `username`, value does not match pattern\"]")]
+fn test_no_match() {
+    let mut s = valid_struct();
+    s.username = "Thou Cheese".to_string();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_invalid_pattern() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/compile_fail/regex/regex_invalid_pattern.rs");
+}
+
+#[derive(Validate)]
+struct Dynamic {
+    pattern: String,
+    #[validate(predicate = "vale::regex_matches(&self.value, &self.pattern)")]
+    value: String,
+}
+
+#[test]
+fn test_dynamic_pattern() {
+    let mut dynamic = Dynamic { pattern: "^[0-9]+$".to_string(), value: "1234".to_string() };
+    dynamic.validate().unwrap();
+    dynamic.pattern = "^[a-z]+$".to_string();
+    assert_eq!(
+        dynamic.validate().unwrap_err(),
+        ["Failed to validate field `value`, `vale::regex_matches(&self.value, &self.pattern)` does not hold"],
+    );
+}
+
+#[test]
+fn test_dynamic_invalid_pattern() {
+    assert!(vale::regex_matches("1234", "^[0-9]+$"));
+    assert!(!vale::regex_matches("1234", "[0-9"));
+    let mut dynamic = Dynamic { pattern: "[0-9".to_string(), value: "1234".to_string() };
+    assert!(dynamic.validate().is_err());
+}
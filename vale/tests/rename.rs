@@ -0,0 +1,48 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(rename = "Street name", len_gt(0))]
+    street: String,
+}
+
+#[derive(Validate)]
+struct User {
+    #[validate(rename = "User ID", gt(0))]
+    user_id: i32,
+    #[validate(rename = "Home address")]
+    #[validate(nested)]
+    address: Address,
+    #[validate(len_lt(5))]
+    nickname: String,
+}
+
+fn invalid_user() -> User {
+    User {
+        user_id: 0,
+        address: Address { street: String::new() },
+        nickname: "nicknamed".to_string(),
+    }
+}
+
+#[test]
+fn test_renamed() {
+    assert_eq!(
+        invalid_user().validate(),
+        Err(vec![
+            "Failed to validate field `User ID`, value too low".to_string(),
+            "Home address: Failed to validate field `Street name`, value too short".to_string(),
+            "Failed to validate field `nickname`, value too long".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_field_errors_keys() {
+    let errors = invalid_user().validate_fields().unwrap_err();
+    assert_eq!(
+        errors.get("user_id"),
+        Some(&["Failed to validate field `User ID`, value too low".to_string()][..]),
+    );
+    assert!(errors.get("User ID").is_none());
+}
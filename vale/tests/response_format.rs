@@ -0,0 +1,73 @@
+#![cfg(any(feature = "rocket", feature = "actix", feature = "axum", feature = "warp"))]
+
+use vale::ResponseFormat;
+
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(gt(10))]
+    value: u32,
+    #[validate(len_lt(4))]
+    name: String,
+}
+
+fn invalid_struct() -> Struct {
+    Struct {
+        value: 8,
+        name: "Luuk Wester".to_string(),
+    }
+}
+
+#[test]
+fn test_default() {
+    assert_eq!(ResponseFormat::default(), ResponseFormat::JsonArray);
+}
+
+#[test]
+fn test_valid() {
+    let mut s = Struct {
+        value: 12,
+        name: "Luk".to_string(),
+    };
+    for format in [ResponseFormat::JsonArray, ResponseFormat::JsonObject, ResponseFormat::Text] {
+        assert!(format.validate(&mut s).is_ok());
+    }
+}
+
+#[test]
+fn test_json_array() {
+    let body = ResponseFormat::JsonArray.validate(&mut invalid_struct()).unwrap_err();
+    assert_eq!(body.format(), ResponseFormat::JsonArray);
+    assert_eq!(body.content_type(), "application/json");
+    assert_eq!(
+        body.body(),
+        r#"["Failed to validate field `value`, value too low","Failed to validate field `name`, value too long"]"#,
+    );
+}
+
+#[test]
+fn test_json_object() {
+    let body = ResponseFormat::JsonObject.validate(&mut invalid_struct()).unwrap_err();
+    assert_eq!(body.content_type(), "application/json");
+    assert_eq!(
+        body.body(),
+        r#"{"value":["Failed to validate field `value`, value too low"],"name":["Failed to validate field `name`, value too long"]}"#,
+    );
+    assert_eq!(
+        body.errors(),
+        [
+            "Failed to validate field `value`, value too low",
+            "Failed to validate field `name`, value too long",
+        ],
+    );
+}
+
+#[test]
+fn test_text() {
+    let body = ResponseFormat::Text.validate(&mut invalid_struct()).unwrap_err();
+    assert_eq!(body.content_type(), "text/plain; charset=utf-8");
+    assert_eq!(
+        body.body(),
+        "Failed to validate field `value`, value too low\n\
+         Failed to validate field `name`, value too long",
+    );
+}
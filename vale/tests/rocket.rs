@@ -1,9 +1,17 @@
-#![feature(decl_macro)]
+#![feature(decl_macro, proc_macro_hygiene)]
+#![cfg(feature = "rocket_contrib")]
 
 extern crate rkt as rocket;
 
 use rkt_contrib::json::Json;
-use rkt::http::Status;
+use rkt::http::{ContentType, Status};
+use rkt::request::Form;
+
+/// The status of a request whose body was parsed, but failed validation.
+#[cfg(not(feature = "unprocessable_entity"))]
+const VALIDATION_FAILED: Status = Status::BadRequest;
+#[cfg(feature = "unprocessable_entity")]
+const VALIDATION_FAILED: Status = Status::UnprocessableEntity;
 
 #[derive(vale::Validate)]
 #[derive(serde::Serialize, serde::Deserialize)]
@@ -42,8 +50,31 @@ fn route(to_validate: vale::Valid<Json<Struct>>) -> rkt_contrib::json::Json<Stru
     rkt_contrib::json::Json(to_validate.into_inner().into_inner())
 }
 
+#[rocket::post("/many", data = "<to_validate>")]
+fn many_route(to_validate: vale::Valid<Json<Vec<Struct>>>) -> rkt_contrib::json::Json<Vec<Struct>> {
+    rkt_contrib::json::Json(to_validate.into_inner().into_inner())
+}
+
+#[rocket::post("/stream", data = "<to_validate>")]
+fn stream_route(to_validate: vale::ValidStream<Struct>) -> String {
+    to_validate.len().to_string()
+}
+
+#[derive(vale::Validate, rocket::FromForm)]
+struct Login {
+    #[validate(trim, len_gt(2))]
+    name: String,
+    #[validate(gte(18))]
+    age: u32,
+}
+
+#[rocket::post("/form", data = "<login>")]
+fn form_route(login: vale::Valid<Form<Login>>) -> String {
+    login.into_inner().into_inner().name
+}
+
 fn test_rocket() -> rocket::Rocket {
-    rocket::ignite().mount("/", rocket::routes![route])
+    rocket::ignite().mount("/", rocket::routes![route, many_route, stream_route, form_route])
 }
 
 #[test]
@@ -70,7 +101,7 @@ fn test_too_small() {
         .post("/")
         .body(serde_json::to_string(&s).unwrap())
         .dispatch();
-    assert_eq!(resp.status(), Status::BadRequest)
+    assert_eq!(resp.status(), VALIDATION_FAILED)
 }
 
 #[test]
@@ -84,7 +115,7 @@ fn test_too_short() {
         .post("/")
         .body(serde_json::to_string(&s).unwrap())
         .dispatch();
-    assert_eq!(resp.status(), Status::BadRequest)
+    assert_eq!(resp.status(), VALIDATION_FAILED)
 }
 
 #[test]
@@ -98,7 +129,7 @@ fn test_too_false() {
         .post("/")
         .body(serde_json::to_string(&s).unwrap())
         .dispatch();
-    assert_eq!(resp.status(), Status::BadRequest)
+    assert_eq!(resp.status(), VALIDATION_FAILED)
 }
 
 #[test]
@@ -127,5 +158,183 @@ fn transfail() {
         .body(serde_json::to_string(&s).unwrap())
         .dispatch();
     println!("{:?}", resp.body_string());
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+}
+
+#[rocket::catch(400)]
+fn bad_request(req: &rocket::Request) -> String {
+    vale::validation_errors(req).map(|errors| errors.join("\n")).unwrap_or_default()
+}
+
+#[rocket::catch(422)]
+fn unprocessable_entity(req: &rocket::Request) -> String {
+    vale::validation_errors(req).map(|errors| errors.join("\n")).unwrap_or_default()
+}
+
+#[test]
+fn catcher() {
+    let mut s = valid_struct();
+    s.value = 8;
+    s.string = "hi".to_string();
+
+    let rocket = test_rocket().register(rocket::catchers![bad_request, unprocessable_entity]);
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/")
+        .body(serde_json::to_string(&s).unwrap())
+        .dispatch();
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+    assert_eq!(
+        resp.body_string().unwrap(),
+        "Failed to validate field `value`, value too low\nFailed to validate field `string`, value too short",
+    );
+}
+
+#[test]
+fn catcher_without_validation() {
+    let rocket = test_rocket().register(rocket::catchers![bad_request, unprocessable_entity]);
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client.post("/").body("not json").dispatch();
     assert_eq!(resp.status(), Status::BadRequest);
+    assert_eq!(resp.body_string().unwrap(), "");
+}
+
+#[test]
+fn vec_valid() {
+    let mut s = valid_struct();
+    s.transformer = "     CAST ME       ".to_string();
+
+    let rocket = test_rocket();
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/many")
+        .body(serde_json::to_string(&vec![valid_struct(), s]).unwrap())
+        .dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    let resp: Vec<Struct> = serde_json::from_str(&resp.body_string().unwrap()).unwrap();
+    assert_eq!(resp[1].transformer, "cast me");
+}
+
+#[test]
+fn vec_invalid() {
+    let mut s = valid_struct();
+    s.value = 8;
+
+    let rocket = test_rocket().register(rocket::catchers![bad_request, unprocessable_entity]);
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/many")
+        .body(serde_json::to_string(&vec![valid_struct(), valid_struct(), s]).unwrap())
+        .dispatch();
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+    assert_eq!(
+        resp.body_string().unwrap(),
+        "[2]: Failed to validate field `value`, value too low",
+    );
+}
+
+#[test]
+fn stream_valid() {
+    let items: Vec<Struct> = (0..1000).map(|_| valid_struct()).collect();
+
+    let rocket = test_rocket();
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/stream")
+        .body(serde_json::to_string(&items).unwrap())
+        .dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    assert_eq!(resp.body_string().unwrap(), "1000");
+}
+
+#[test]
+fn stream_invalid() {
+    let mut items: Vec<Struct> = (0..1000).map(|_| valid_struct()).collect();
+    items[500].value = 8;
+    items[700].string = "hi".to_string();
+
+    let rocket = test_rocket().register(rocket::catchers![bad_request, unprocessable_entity]);
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/stream")
+        .body(serde_json::to_string(&items).unwrap())
+        .dispatch();
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+    assert_eq!(
+        resp.body_string().unwrap(),
+        "[500]: Failed to validate field `value`, value too low",
+    );
+}
+
+#[test]
+fn stream_malformed() {
+    let rocket = test_rocket().register(rocket::catchers![bad_request, unprocessable_entity]);
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let resp = client.post("/stream").body("[{\"value\": 12").dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+}
+
+#[test]
+fn status_codes() {
+    let mut s = valid_struct();
+    s.value = 8;
+
+    let rocket = test_rocket();
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let resp = client.post("/").body("not json").dispatch();
+    assert_eq!(resp.status(), Status::BadRequest);
+    let resp = client
+        .post("/")
+        .body(serde_json::to_string(&s).unwrap())
+        .dispatch();
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+}
+
+#[test]
+fn format_errors() {
+    let error = vale::ValidationError::<String>::FromDataError("unexpected end of input".to_string());
+    assert_eq!(error.to_string(), "failed to read the request body: unexpected end of input");
+    assert_eq!(error.into_from_data_error(), Some("unexpected end of input".to_string()));
+
+    let error = vale::ValidationError::<String>::ValidationError(vec!["a".to_string(), "b".to_string()]);
+    assert_eq!(error.to_string(), "validation failed: a, b");
+    assert_eq!(error.into_from_data_error(), None);
+}
+
+#[test]
+fn test_form() {
+    let rocket = test_rocket();
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/form")
+        .header(ContentType::Form)
+        .body("name=+luuk+&age=30")
+        .dispatch();
+    assert_eq!(resp.status(), Status::Ok);
+    assert_eq!(resp.body_string().unwrap(), "luuk");
+    let resp = client
+        .post("/form")
+        .header(ContentType::Form)
+        .body("name=lu&age=30")
+        .dispatch();
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+    let resp = client
+        .post("/form")
+        .header(ContentType::Form)
+        .body("name=luuk&age=old")
+        .dispatch();
+    assert_eq!(resp.status(), Status::UnprocessableEntity);
+}
+
+#[test]
+fn form_catcher() {
+    let rocket = test_rocket().register(rocket::catchers![bad_request, unprocessable_entity]);
+    let client = rkt::local::Client::new(rocket).unwrap();
+    let mut resp = client
+        .post("/form")
+        .header(ContentType::Form)
+        .body("name=lu&age=30")
+        .dispatch();
+    assert_eq!(resp.status(), VALIDATION_FAILED);
+    assert_eq!(resp.body_string().unwrap(), "Failed to validate field `name`, value too short");
 }
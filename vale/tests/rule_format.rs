@@ -0,0 +1,43 @@
+use vale::Validate;
+
+struct Struct {
+    value: u32,
+    name: String,
+}
+
+impl vale::Validate for Struct {
+    #[vale::ruleset]
+    fn validate(&mut self) -> vale::Result {
+        vale::rule!(self.value > 10, "value was {}, expected more than {}", self.value, 10);
+        vale::rule!(self.name.len() < 5, "name `{}` is too long", self.name);
+        vale::rule!(!self.name.is_empty(), "name is empty");
+        vale::rule!(self.value < 100);
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = Struct { value: 12, name: "Luuk".to_string() };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_format_args() {
+    let mut s = Struct { value: 3, name: "ThouCheese".to_string() };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "value was 3, expected more than 10".to_string(),
+            "name `ThouCheese` is too long".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_without_format_args() {
+    let mut s = Struct { value: 200, name: String::new() };
+    assert_eq!(
+        s.validate(),
+        Err(vec!["name is empty".to_string(), "No message provided".to_string()]),
+    );
+}
@@ -0,0 +1,48 @@
+struct Order {
+    quantities: Vec<i32>,
+    note: String,
+}
+
+fn check_quantities(quantities: &[i32], errors: &mut Vec<String>) {
+    for (idx, quantity) in quantities.iter().enumerate() {
+        vale::rule_into!(errors, *quantity > 0, "quantity {} is nonpositive", idx);
+    }
+}
+
+impl vale::Validate for Order {
+    #[vale::ruleset]
+    fn validate(&mut self) -> vale::Result {
+        check_quantities(&self.quantities, &mut errors);
+        vale::rule!(self.note.len() < 10, "Note too long");
+    }
+}
+
+#[test]
+fn test_free_function() {
+    let mut errors = vec!["existing".to_string()];
+    check_quantities(&[1, 0, -1], &mut errors);
+    vale::rule_into!(&mut errors, false);
+    assert_eq!(
+        errors,
+        vec![
+            "existing".to_string(),
+            "quantity 1 is nonpositive".to_string(),
+            "quantity 2 is nonpositive".to_string(),
+            "No message provided".to_string(),
+        ],
+    );
+}
+
+#[test]
+fn test_inside_ruleset() {
+    use vale::Validate;
+
+    let mut order = Order { quantities: vec![1, 2], note: String::new() };
+    order.validate().unwrap();
+    order.quantities.push(0);
+    order.note = "a very long note".to_string();
+    assert_eq!(
+        order.validate(),
+        Err(vec!["quantity 2 is nonpositive".to_string(), "Note too long".to_string()]),
+    );
+}
@@ -0,0 +1,46 @@
+use vale::Validate;
+
+struct Config {
+    port: String,
+    timeout: String,
+}
+
+fn parse_port(port: &str) -> Result<u16, String> {
+    port.parse().map_err(|_| format!("`{}` is not a port", port))
+}
+
+impl Validate for Config {
+    #[vale::ruleset]
+    fn validate(&mut self) -> vale::Result {
+        vale::rule_try!(parse_port(&self.port).map(|port| port >= 1024), "Port {} is reserved", self.port);
+        vale::rule_try!(self.timeout.parse::<u32>().map(|timeout| timeout > 0));
+    }
+}
+
+fn valid_config() -> Config {
+    Config { port: "8080".to_string(), timeout: "30".to_string() }
+}
+
+#[test]
+fn test_valid() {
+    valid_config().validate().unwrap();
+}
+
+#[test]
+fn test_false() {
+    let mut config = valid_config();
+    config.port = "80".to_string();
+    config.timeout = "0".to_string();
+    assert_eq!(config.validate().unwrap_err(), ["Port 80 is reserved", "No message provided"]);
+}
+
+#[test]
+fn test_err() {
+    let mut config = valid_config();
+    config.port = "eighty".to_string();
+    config.timeout = "-1".to_string();
+    assert_eq!(
+        config.validate().unwrap_err(),
+        ["`eighty` is not a port", "invalid digit found in string"],
+    );
+}
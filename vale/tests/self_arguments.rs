@@ -0,0 +1,67 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(lt(self.limit))]
+    value: u32,
+    #[validate(lte(self.limit))]
+    inclusive: u32,
+    limit: u32,
+    #[validate(len_lt(self.max_len))]
+    name: String,
+    #[validate(range(self.range.0, self.range.1))]
+    ranged: i32,
+    range: (i32, i32),
+    max_len: usize,
+    #[validate(lt(self.limit))]
+    optional: Option<u32>,
+    #[validate(each(lt(self.limit)))]
+    scores: Vec<u32>,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        value: 5,
+        inclusive: 10,
+        limit: 10,
+        name: "Luuk".to_string(),
+        ranged: 0,
+        range: (-5, 5),
+        max_len: 10,
+        optional: Some(3),
+        scores: vec![1, 9],
+    }
+}
+
+#[test]
+fn valid() {
+    valid_struct().validate().unwrap();
+    valid_struct().validate_ref().unwrap();
+}
+
+#[test]
+fn above_sibling() {
+    let mut s = valid_struct();
+    s.value = 10;
+    s.inclusive = 11;
+    s.optional = Some(12);
+    s.scores = vec![1, 10];
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `value`, value too high".to_string(),
+            "Failed to validate field `inclusive`, value must be at most self.limit".to_string(),
+            "Failed to validate field `optional`, value too high".to_string(),
+            "Failed to validate field `scores[1]`, value too high".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn sibling_changes() {
+    let mut s = valid_struct();
+    s.limit = 3;
+    s.max_len = 4;
+    s.range = (1, 5);
+    assert_eq!(s.validate().unwrap_err().len(), 6);
+}
@@ -0,0 +1,53 @@
+use vale::Validate;
+
+#[derive(Validate)]
+#[validate(show_values)]
+struct Struct {
+    #[validate(gt(0))]
+    id: i32,
+    #[validate(trim, len_gt(2))]
+    name: String,
+    #[validate(each(lt(10)))]
+    scores: Vec<u8>,
+    #[validate(required)]
+    email: Option<String>,
+    #[validate(lt(5, message = "custom"))]
+    custom: i32,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        id: 1,
+        name: "Luuk".to_string(),
+        scores: vec![1],
+        email: Some("luuk@example.com".to_string()),
+        custom: 0,
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_values_shown() {
+    let mut s = Struct {
+        id: -3,
+        name: " a ".to_string(),
+        scores: vec![1, 12],
+        email: None,
+        custom: 7,
+    };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `id` = -3, value too low".to_string(),
+            "Failed to validate field `name` = \"a\", value too short".to_string(),
+            "Failed to validate field `scores[1]` = 12, value too high".to_string(),
+            "Failed to validate field `email` = None, value is required".to_string(),
+            "custom".to_string(),
+        ]),
+    );
+}
@@ -0,0 +1,50 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Signed {
+    #[validate(positive)]
+    positive: i32,
+    #[validate(negative)]
+    negative: i32,
+    #[validate(non_zero)]
+    non_zero: i32,
+}
+
+#[derive(Validate)]
+struct Unsigned {
+    #[validate(positive)]
+    positive: u32,
+    #[validate(non_zero)]
+    non_zero: f64,
+}
+
+#[test]
+fn test_signed() {
+    let mut s = Signed { positive: 1, negative: -1, non_zero: -3 };
+    s.validate().unwrap();
+    let mut s = Signed { positive: 0, negative: 0, non_zero: 0 };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `positive`, value is not positive".to_string(),
+            "Failed to validate field `negative`, value is not negative".to_string(),
+            "Failed to validate field `non_zero`, value is zero".to_string(),
+        ]),
+    );
+    let mut s = Signed { positive: -5, negative: 5, non_zero: 5 };
+    assert_eq!(s.validate().unwrap_err().len(), 2);
+}
+
+#[test]
+fn test_unsigned() {
+    let mut s = Unsigned { positive: 1, non_zero: 0.5 };
+    s.validate().unwrap();
+    let mut s = Unsigned { positive: 0, non_zero: 0.0 };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `positive`, value is not positive".to_string(),
+            "Failed to validate field `non_zero`, value is zero".to_string(),
+        ]),
+    );
+}
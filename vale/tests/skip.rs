@@ -0,0 +1,27 @@
+use vale::Validate;
+
+struct Opaque;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(gt(0))]
+    id: i32,
+    #[validate(skip)]
+    #[allow(dead_code)]
+    opaque: Opaque,
+    #[validate(skip, gt(0))]
+    #[allow(dead_code)]
+    ignored: i32,
+}
+
+#[test]
+fn test_valid() {
+    let mut s = Struct { id: 1, opaque: Opaque, ignored: -1 };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    let mut s = Struct { id: 0, opaque: Opaque, ignored: -1 };
+    assert_eq!(s.validate(), Err(vec!["Failed to validate field `id`, value too low".to_string()]));
+}
@@ -0,0 +1,48 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(starts_with_any("http://", "https://"))]
+    link: String,
+    #[validate(ends_with_any(".png", ".jpg", ".gif"))]
+    image: String,
+    #[validate(starts_with_any('+', '0'))]
+    phone: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        link: "https://example.com".to_string(),
+        image: "cheese.jpg".to_string(),
+        phone: "+31 20 123 4567".to_string(),
+    }
+}
+
+#[test]
+fn test_matches_one() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    s.link = "http://example.com".to_string();
+    s.image = "cheese.gif".to_string();
+    s.phone = "020 123 4567".to_string();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_matches_none() {
+    let mut s = Struct {
+        link: "ftp://example.com".to_string(),
+        image: "cheese.bmp".to_string(),
+        phone: "20 123 4567".to_string(),
+    };
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `link`, value does not start with any of \"http://\", \"https://\""
+                .to_string(),
+            "Failed to validate field `image`, value does not end with any of \".png\", \".jpg\", \".gif\""
+                .to_string(),
+            "Failed to validate field `phone`, value does not start with any of '+', '0'".to_string(),
+        ]),
+    );
+}
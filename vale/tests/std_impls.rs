@@ -0,0 +1,65 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct User {
+    #[validate(len_gt(2))]
+    name: String,
+    #[validate(trim)]
+    nickname: String,
+}
+
+fn user(name: &str) -> User {
+    User { name: name.to_string(), nickname: " nick ".to_string() }
+}
+
+#[test]
+fn test_valid() {
+    let mut users = vec![user("Luuk"), user("Vale")];
+    users.validate().unwrap();
+    assert!(users.iter().all(|u| u.nickname == "nick"));
+}
+
+#[test]
+fn test_third_invalid() {
+    let mut users = vec![user("Luuk"), user("Vale"), user("Al"), user("Rust")];
+    assert_eq!(
+        users.validate(),
+        Err(vec!["[2]: Failed to validate field `name`, value too short".to_string()]),
+    );
+}
+
+#[test]
+fn test_box() {
+    let mut user = Box::new(user("Al"));
+    assert!(user.validate().is_err());
+}
+
+#[test]
+fn test_option() {
+    let mut valid = Some(user("Luuk"));
+    valid.validate().unwrap();
+    assert_eq!(valid.unwrap().nickname, "nick");
+
+    let mut invalid = Some(user("Al"));
+    assert_eq!(
+        invalid.validate(),
+        Err(vec!["Failed to validate field `name`, value too short".to_string()]),
+    );
+
+    let mut none: Option<User> = None;
+    none.validate().unwrap();
+}
+
+#[test]
+fn test_validate_all() {
+    let mut users = [user("Al"), user("Luuk"), user("Jo")];
+    assert_eq!(
+        vale::validate_all(&mut users),
+        Err(vec![
+            "[0]: Failed to validate field `name`, value too short".to_string(),
+            "[2]: Failed to validate field `name`, value too short".to_string(),
+        ]),
+    );
+    assert!(users.iter().all(|u| u.nickname == "nick"));
+    vale::validate_all(&mut users[1..2]).unwrap();
+}
@@ -0,0 +1,78 @@
+#![cfg(feature = "chrono")]
+
+extern crate chr as chrono;
+
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, TimeZone, Utc};
+use vale::Validate;
+
+#[derive(Validate)]
+struct Event {
+    #[validate(future)]
+    starts_at: DateTime<Utc>,
+    #[validate(past)]
+    created_at: NaiveDateTime,
+    #[validate(past)]
+    birthday: Option<NaiveDate>,
+}
+
+fn valid_event() -> Event {
+    Event {
+        starts_at: Utc.with_ymd_and_hms(3000, 1, 1, 0, 0, 0).unwrap(),
+        created_at: NaiveDate::from_ymd_opt(2020, 1, 1).unwrap().and_hms_opt(12, 0, 0).unwrap(),
+        birthday: NaiveDate::from_ymd_opt(1990, 5, 17),
+    }
+}
+
+#[test]
+fn valid() {
+    valid_event().validate().unwrap();
+}
+
+#[test]
+fn not_in_the_future() {
+    let mut s = valid_event();
+    s.starts_at = Utc.with_ymd_and_hms(2000, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `starts_at`, value is not in the future".to_string()]),
+    );
+}
+
+#[test]
+fn not_in_the_past() {
+    let mut s = valid_event();
+    s.birthday = NaiveDate::from_ymd_opt(3000, 1, 1);
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `birthday`, value is not in the past".to_string()]),
+    );
+}
+
+#[test]
+fn fixed_clock() {
+    let now = Utc.with_ymd_and_hms(2020, 6, 1, 12, 0, 0).unwrap();
+    assert!(vale::is_future_at(&(now + Duration::seconds(1)), &now));
+    assert!(!vale::is_future_at(&now, &now));
+    assert!(vale::is_past_at(&(now - Duration::seconds(1)), &now));
+    assert!(!vale::is_past_at(&now, &now));
+}
+
+#[test]
+fn fixed_clock_dates() {
+    let now = Utc.with_ymd_and_hms(2020, 6, 1, 23, 59, 59).unwrap();
+    let today = NaiveDate::from_ymd_opt(2020, 6, 1).unwrap();
+    assert!(!vale::is_future_at(&today, &now));
+    assert!(!vale::is_past_at(&today, &now));
+    assert!(vale::is_future_at(&today.succ_opt().unwrap(), &now));
+    assert!(vale::is_past_at(&today.pred_opt().unwrap(), &now));
+}
+
+#[test]
+fn fixed_clock_time_zones() {
+    let now = Utc.with_ymd_and_hms(2020, 6, 1, 12, 0, 0).unwrap();
+    let amsterdam = chrono::FixedOffset::east_opt(2 * 3600).unwrap();
+    let later = amsterdam.with_ymd_and_hms(2020, 6, 1, 13, 30, 0).unwrap();
+    assert!(vale::is_past_at(&later, &now));
+    let later = amsterdam.with_ymd_and_hms(2020, 6, 1, 14, 30, 0).unwrap();
+    assert!(vale::is_future_at(&later, &now));
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "tower")]
+
+use std::convert::Infallible;
+use tower::ServiceExt;
+use vale::tower::ValidateLayer;
+use tower::Layer;
+
+#[derive(vale::Validate)]
+struct Struct {
+    #[validate(gt(10))]
+    value: u32,
+    #[validate(trim, len_lt(10))]
+    name: String,
+}
+
+async fn handler(req: http::Request<Struct>) -> Result<http::Response<String>, Infallible> {
+    Ok(http::Response::new(req.into_body().name))
+}
+
+fn request(value: u32, name: &str) -> http::Request<Struct> {
+    http::Request::new(Struct { value, name: name.to_string() })
+}
+
+#[tokio::test]
+async fn test_valid() {
+    let service = ValidateLayer::new().layer(tower::service_fn(handler));
+    let resp = service.oneshot(request(12, "  luuk  ")).await.unwrap();
+    assert_eq!(resp.status(), http::StatusCode::OK);
+    assert_eq!(resp.body(), "luuk");
+}
+
+#[tokio::test]
+async fn test_invalid() {
+    let service = ValidateLayer::new().layer(tower::service_fn(handler));
+    let resp = service.oneshot(request(8, "a very long name")).await.unwrap();
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()[http::header::CONTENT_TYPE], "application/json");
+    let errors: Vec<String> = serde_json::from_str(resp.body()).unwrap();
+    assert_eq!(
+        errors,
+        vec![
+            "Failed to validate field `value`, value too low".to_string(),
+            "Failed to validate field `name`, value too long".to_string(),
+        ],
+    );
+}
+
+#[tokio::test]
+async fn test_response_format() {
+    let service = ValidateLayer::new().layer(tower::service_fn(handler));
+    let mut req = request(8, "luuk");
+    req.extensions_mut().insert(vale::ResponseFormat::Text);
+    let resp = service.oneshot(req).await.unwrap();
+    assert_eq!(resp.status(), http::StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()[http::header::CONTENT_TYPE], "text/plain; charset=utf-8");
+    assert_eq!(resp.body(), "Failed to validate field `value`, value too low");
+}
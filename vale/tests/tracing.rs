@@ -0,0 +1,99 @@
+#![cfg(feature = "tracing")]
+
+extern crate trc as tracing;
+
+use std::sync::{Arc, Mutex};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+use vale::Validate;
+
+#[derive(Validate)]
+struct User {
+    #[validate(gt(0))]
+    id: i32,
+    #[validate(len_gt(0))]
+    name: String,
+}
+
+/// Records the fields of the spans and events it sees, formatted as `name=value`.
+#[derive(Clone, Default)]
+struct Capture {
+    spans: Arc<Mutex<Vec<String>>>,
+    events: Arc<Mutex<Vec<String>>>,
+}
+
+struct Fields(String);
+
+impl Visit for Fields {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        self.0.push_str(&format!("{}={:?} ", field.name(), value));
+    }
+}
+
+impl Subscriber for Capture {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut fields = Fields(format!("{} ", span.metadata().name()));
+        span.record(&mut fields);
+        let mut spans = self.spans.lock().unwrap();
+        spans.push(fields.0.trim_end().to_string());
+        Id::from_u64(spans.len() as u64)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let mut fields = Fields(String::new());
+        event.record(&mut fields);
+        self.events.lock().unwrap().push(fields.0.trim_end().to_string());
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[test]
+fn span_per_validation() {
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || {
+        User { id: 1, name: "Luuk".to_string() }.validate().unwrap();
+        User { id: 0, name: String::new() }.validate().unwrap_err();
+    });
+    assert_eq!(
+        *capture.spans.lock().unwrap(),
+        vec!["validate entity=\"User\"".to_string(), "validate entity=\"User\"".to_string()],
+    );
+    assert_eq!(
+        *capture.events.lock().unwrap(),
+        vec!["message=validated User errors=0".to_string(), "message=validated User errors=2".to_string()],
+    );
+}
+
+#[derive(Validate)]
+#[validate(max_errors = 1)]
+struct Capped {
+    #[validate(gt(0))]
+    id: i32,
+    #[validate(len_gt(0))]
+    name: String,
+}
+
+#[test]
+fn exit_event_with_max_errors() {
+    let capture = Capture::default();
+    tracing::subscriber::with_default(capture.clone(), || {
+        Capped { id: 1, name: "Luuk".to_string() }.validate().unwrap();
+        Capped { id: 0, name: String::new() }.validate().unwrap_err();
+    });
+    assert_eq!(
+        *capture.events.lock().unwrap(),
+        vec!["message=validated Capped errors=0".to_string(), "message=validated Capped errors=1".to_string()],
+    );
+}
@@ -0,0 +1,31 @@
+use vale::Validate;
+
+#[derive(Validate)]
+#[validate(transform_first)]
+struct TransformFirst {
+    #[validate(len_lt(5), trim)]
+    name: String,
+    #[validate(eq("hello"), to_lower_case, len_eq(5), trim)]
+    greeting: String,
+}
+
+#[derive(Validate)]
+struct InOrder {
+    #[validate(len_lt(5), trim)]
+    name: String,
+}
+
+#[test]
+fn test_transform_first() {
+    let mut s = TransformFirst { name: "   hi  ".to_string(), greeting: "  HeLLo ".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.name, "hi");
+    assert_eq!(s.greeting, "hello");
+}
+
+#[test]
+fn test_in_order() {
+    let mut s = InOrder { name: "   hi  ".to_string() };
+    assert_eq!(s.validate(), Err(vec!["Failed to validate field `name`, value too long".to_string()]));
+    assert_eq!(s.name, "hi");
+}
@@ -0,0 +1,179 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(to_upper_case)]
+    upper: String,
+    #[validate(trim, to_upper_case)]
+    trim_first: String,
+    #[validate(to_upper_case, trim)]
+    trim_last: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        upper: "abc".to_string(),
+        trim_first: "  abc  ".to_string(),
+        trim_last: "  abc  ".to_string(),
+    }
+}
+
+#[test]
+fn to_upper_case() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    assert_eq!(s.upper, "ABC");
+}
+
+#[test]
+fn to_upper_case_with_trim() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    assert_eq!(s.trim_first, "ABC");
+    assert_eq!(s.trim_last, "ABC");
+}
+
+#[derive(Validate)]
+struct Name {
+    #[validate(capitalize)]
+    name: String,
+}
+
+#[test]
+fn capitalize() {
+    let mut s = Name { name: "john".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.name, "John");
+}
+
+#[test]
+fn capitalize_already_capitalized() {
+    let mut s = Name { name: "John mcClane".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.name, "John mcClane");
+}
+
+#[test]
+fn capitalize_empty() {
+    let mut s = Name { name: String::new() };
+    s.validate().unwrap();
+    assert_eq!(s.name, "");
+}
+
+#[test]
+fn capitalize_multibyte() {
+    assert_eq!(vale::capitalize("élan"), "Élan");
+    assert_eq!(vale::capitalize("ßtraße"), "SStraße");
+}
+
+#[derive(Validate)]
+struct Post {
+    #[validate(truncate(5))]
+    text: String,
+}
+
+#[test]
+fn truncate_ascii() {
+    let mut s = Post { text: "hello world".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.text, "hello");
+}
+
+#[test]
+fn truncate_multibyte() {
+    let mut s = Post { text: "👨‍👩‍👧 🧀🧀🧀".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.text, "👨‍👩‍👧");
+    let mut s = Post { text: "ééééééé".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.text, "ééééé");
+}
+
+#[test]
+fn truncate_short() {
+    let mut s = Post { text: "hi".to_string() };
+    s.validate().unwrap();
+    assert_eq!(s.text, "hi");
+}
+
+#[derive(Validate)]
+struct Types {
+    #[validate(trim, to_lower_case, truncate(4), capitalize)]
+    string: std::string::String,
+    #[validate(trim, to_upper_case)]
+    optional: Option<String>,
+    #[validate(each(trim, truncate(2)))]
+    list: Vec<String>,
+    #[validate(trim, to_lower_case)]
+    boxed: Box<str>,
+}
+
+#[test]
+fn transformer_types() {
+    let mut s = Types {
+        string: "  HELLO  ".to_string(),
+        optional: Some(" hi ".to_string()),
+        list: vec![" abc ".to_string()],
+        boxed: " BOX ".into(),
+    };
+    s.validate().unwrap();
+    assert_eq!(s.string, "Hell");
+    assert_eq!(s.optional.as_deref(), Some("HI"));
+    assert_eq!(s.list, vec!["ab".to_string()]);
+    assert_eq!(&*s.boxed, "box");
+}
+
+#[derive(Validate)]
+struct Form {
+    #[validate(trim, default_if_empty("N/A"), len_gt(0))]
+    title: String,
+    #[validate(default_if_empty("none"))]
+    boxed: Box<str>,
+}
+
+#[test]
+fn default_if_empty() {
+    let mut s = Form { title: "   ".to_string(), boxed: "".into() };
+    s.validate().unwrap();
+    assert_eq!(s.title, "N/A");
+    assert_eq!(&*s.boxed, "none");
+}
+
+#[test]
+fn default_if_empty_untouched() {
+    let mut s = Form { title: " hello ".to_string(), boxed: "box".into() };
+    s.validate().unwrap();
+    assert_eq!(s.title, "hello");
+    assert_eq!(&*s.boxed, "box");
+}
+
+struct Title(String);
+
+impl vale::Transform for Title {
+    fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    fn from_string(s: String) -> Self {
+        Title(s)
+    }
+}
+
+#[derive(Validate)]
+struct Custom<'a> {
+    #[validate(trim, capitalize)]
+    title: Title,
+    #[validate(to_upper_case)]
+    cow: std::borrow::Cow<'a, str>,
+    #[validate(trim)]
+    shared: std::rc::Rc<str>,
+}
+
+#[test]
+fn transform_trait() {
+    let mut s = Custom { title: Title(" john ".to_string()), cow: "cow".into(), shared: " rc ".into() };
+    s.validate().unwrap();
+    assert_eq!(s.title.0, "John");
+    assert_eq!(s.cow, "COW");
+    assert_eq!(&*s.shared, "rc");
+}
@@ -0,0 +1,44 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(trimmed)]
+    name: String,
+    #[validate(trimmed)]
+    nickname: Option<String>,
+    #[validate(each(trimmed))]
+    tags: Vec<String>,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        name: "Luuk Wester".to_string(),
+        nickname: Some("ThouCheese".to_string()),
+        tags: vec!["rust".to_string(), String::new()],
+    }
+}
+
+#[test]
+fn clean() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    s.validate_ref().unwrap();
+    assert_eq!(s.name, "Luuk Wester");
+}
+
+#[test]
+fn padded() {
+    let mut s = valid_struct();
+    s.name = " Luuk".to_string();
+    s.nickname = Some("ThouCheese\n".to_string());
+    s.tags = vec!["rust\t".to_string()];
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `name`, value has leading or trailing whitespace".to_string(),
+            "Failed to validate field `nickname`, value has leading or trailing whitespace".to_string(),
+            "Failed to validate field `tags[0]`, value has leading or trailing whitespace".to_string(),
+        ]),
+    );
+    assert_eq!(s.name, " Luuk");
+}
@@ -0,0 +1,54 @@
+use vale::Validate;
+
+struct Form {
+    age_input: String,
+    age: u32,
+    ratio_input: String,
+    ratio: f64,
+}
+
+impl Validate for Form {
+    #[vale::ruleset]
+    fn validate(&mut self) -> vale::Result {
+        vale::try_transform!(self.age = self.age_input.trim().parse(), "`{}` is not an age", self.age_input);
+        vale::rule!(self.age < 150, "Too old");
+        vale::try_transform!(self.ratio = self.ratio_input.parse::<f64>());
+    }
+}
+
+fn valid_form() -> Form {
+    Form {
+        age_input: " 42 ".to_string(),
+        age: 0,
+        ratio_input: "0.5".to_string(),
+        ratio: 0.0,
+    }
+}
+
+#[test]
+fn transforms() {
+    let mut s = valid_form();
+    s.validate().unwrap();
+    assert_eq!(s.age, 42);
+    assert_eq!(s.ratio, 0.5);
+}
+
+#[test]
+fn failure_continues() {
+    let mut s = valid_form();
+    s.age_input = "old".to_string();
+    s.age = 200;
+    assert_eq!(
+        s.validate(),
+        Err(vec!["`old` is not an age".to_string(), "Too old".to_string()]),
+    );
+    assert_eq!(s.age, 200);
+    assert_eq!(s.ratio, 0.5);
+}
+
+#[test]
+fn error_as_message() {
+    let mut s = valid_form();
+    s.ratio_input = "half".to_string();
+    assert_eq!(s.validate(), Err(vec!["invalid float literal".to_string()]));
+}
@@ -0,0 +1,43 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(try_with(check_username))]
+    username: String,
+    #[validate(try_with(check_username, message = "invalid alias"))]
+    alias: String,
+}
+
+fn check_username(name: &mut String) -> Result<(), String> {
+    if let Some(c) = name.chars().find(|c| !c.is_ascii_alphanumeric()) {
+        return Err(format!("username `{}` contains the illegal character `{}`", name, c));
+    }
+    Ok(())
+}
+
+fn valid_struct() -> Struct {
+    Struct { username: "ThouCheese".to_string(), alias: "Luuk".to_string() }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_error_message() {
+    let mut s = valid_struct();
+    s.username = "thou cheese".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["username `thou cheese` contains the illegal character ` `".to_string()]),
+    );
+}
+
+#[test]
+fn test_message_override() {
+    let mut s = valid_struct();
+    s.alias = "lu-uk".to_string();
+    assert_eq!(s.validate(), Err(vec!["invalid alias".to_string()]));
+}
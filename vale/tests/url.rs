@@ -0,0 +1,41 @@
+#![cfg(feature = "url")]
+
+use vale::Validate;
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(url)]
+    homepage: String,
+    #[validate(url(scheme = "https"))]
+    callback: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        homepage: "http://example.com".to_string(),
+        callback: "https://example.com/callback?state=1".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_relative() {
+    let mut s = valid_struct();
+    s.homepage = "/index.html".to_string();
+    assert_eq!(s.validate(), Err(vec!["Failed to validate field `homepage`, not a valid url".to_string()]));
+}
+
+#[test]
+fn test_scheme_mismatch() {
+    let mut s = valid_struct();
+    s.callback = "http://example.com/callback".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec!["Failed to validate field `callback`, not a valid `https` url".to_string()]),
+    );
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "uuid")]
+
+use vale::Validate;
+
+#[derive(Validate)]
+struct Resource {
+    #[validate(uuid)]
+    id: String,
+    #[validate(uuid(version = 4))]
+    request_id: String,
+}
+
+#[test]
+fn test_valid() {
+    let mut resource = Resource {
+        id: "67e55044-10b1-426f-9247-bb680e5fe0c8".to_string(),
+        request_id: "936da01f-9abd-4d9d-80c7-02af85c822a8".to_string(),
+    };
+    resource.validate().unwrap();
+}
+
+#[test]
+fn test_malformed() {
+    let mut resource = Resource {
+        id: "67e55044-10b1-426f-9247".to_string(),
+        request_id: "not a uuid".to_string(),
+    };
+    let errors = resource.validate().unwrap_err();
+    assert_eq!(
+        errors,
+        [
+            "Failed to validate field `id`, not a valid UUID",
+            "Failed to validate field `request_id`, not a valid version 4 UUID",
+        ],
+    );
+}
+
+#[test]
+fn test_wrong_version() {
+    let mut resource = Resource {
+        id: "67e55044-10b1-126f-9247-bb680e5fe0c8".to_string(),
+        request_id: "67e55044-10b1-126f-9247-bb680e5fe0c8".to_string(),
+    };
+    let errors = resource.validate().unwrap_err();
+    assert_eq!(errors, ["Failed to validate field `request_id`, not a valid version 4 UUID"]);
+}
@@ -0,0 +1,56 @@
+use vale::{Validate, ValidateRef};
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(gt(0))]
+    id: i32,
+    #[validate(required, len_gt(3))]
+    name: Option<String>,
+    #[validate(each(lt(10)))]
+    scores: Vec<u8>,
+    #[validate(email)]
+    email: String,
+}
+
+#[derive(Validate)]
+enum Enum {
+    Number(#[validate(lt(10))] i32),
+    Empty,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        id: 1,
+        name: Some("ThouCheese".to_string()),
+        scores: vec![1, 2],
+        email: "luuk@example.com".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    let s = valid_struct();
+    s.validate_ref().unwrap();
+}
+
+#[test]
+fn test_invalid() {
+    let mut s = valid_struct();
+    s.id = 0;
+    s.name = Some("abc".to_string());
+    s.scores[1] = 12;
+    let expected = vec![
+        "Failed to validate field `id`, value too low".to_string(),
+        "Failed to validate field `name`, value too short".to_string(),
+        "Failed to validate field `scores[1]`, value too high".to_string(),
+    ];
+    assert_eq!(s.validate_ref(), Err(expected.clone()));
+    assert_eq!(s.validate(), Err(expected));
+}
+
+#[test]
+fn test_enum() {
+    assert!(Enum::Number(3).validate_ref().is_ok());
+    assert!(Enum::Number(12).validate_ref().is_err());
+    assert!(Enum::Empty.validate_ref().is_ok());
+}
@@ -0,0 +1,47 @@
+use vale::ValidateWith;
+
+struct AppContext {
+    allowed_roles: Vec<&'static str>,
+    max_name_len: usize,
+}
+
+struct User {
+    name: String,
+    role: String,
+}
+
+impl ValidateWith<AppContext> for User {
+    #[vale::ruleset]
+    fn validate_with(&mut self, ctx: &AppContext) -> vale::Result {
+        self.name = self.name.trim().to_string();
+        vale::rule!(self.name.len() <= ctx.max_name_len, "name too long");
+        vale::rule!(ctx.allowed_roles.contains(&self.role.as_str()), "role `{}` not allowed", self.role);
+    }
+}
+
+fn context() -> AppContext {
+    AppContext { allowed_roles: vec!["admin", "user"], max_name_len: 5 }
+}
+
+#[test]
+fn test_valid() {
+    let mut u = User { name: " Luuk ".to_string(), role: "admin".to_string() };
+    u.validate_with(&context()).unwrap();
+    assert_eq!(u.name, "Luuk");
+}
+
+#[test]
+fn test_invalid() {
+    let mut u = User { name: "ThouCheese".to_string(), role: "root".to_string() };
+    assert_eq!(
+        u.validate_with(&context()),
+        Err(vec!["name too long".to_string(), "role `root` not allowed".to_string()]),
+    );
+}
+
+#[test]
+fn test_other_context() {
+    let ctx = AppContext { allowed_roles: vec!["root"], max_name_len: 20 };
+    let mut u = User { name: "ThouCheese".to_string(), role: "root".to_string() };
+    u.validate_with(&ctx).unwrap();
+}
@@ -0,0 +1,67 @@
+#[derive(vale::Validate)]
+struct Order {
+    #[validate(trim, len_gt(0))]
+    product: String,
+    #[validate(gt(0))]
+    amount: u32,
+}
+
+fn valid_order() -> Order {
+    Order { product: " cheese ".to_string(), amount: 2 }
+}
+
+fn place(order: Order, placed: &mut Vec<String>) -> vale::Result {
+    let order = vale::validated!(order);
+    placed.push(format!("{} x {}", order.amount, order.product));
+    Ok(())
+}
+
+#[derive(Debug, PartialEq)]
+enum Error {
+    Invalid(usize),
+}
+
+fn place_mapped(order: Order) -> Result<u32, Error> {
+    let order = vale::validated!(order, |errors: Vec<String>| Error::Invalid(errors.len()));
+    Ok(order.amount)
+}
+
+#[test]
+fn test_valid() {
+    let mut placed = Vec::new();
+    place(valid_order(), &mut placed).unwrap();
+    assert_eq!(placed, ["2 x cheese"]);
+}
+
+#[test]
+fn test_invalid() {
+    let mut placed = Vec::new();
+    let order = Order { product: "   ".to_string(), amount: 0 };
+    assert_eq!(
+        place(order, &mut placed).unwrap_err(),
+        [
+            "Failed to validate field `product`, value too short",
+            "Failed to validate field `amount`, value too low",
+        ],
+    );
+    assert!(placed.is_empty());
+}
+
+#[test]
+fn test_by_reference() {
+    fn check(order: &mut Order) -> vale::Result {
+        vale::validated!(&mut *order);
+        Ok(())
+    }
+
+    let mut order = valid_order();
+    check(&mut order).unwrap();
+    assert_eq!(order.product, "cheese");
+}
+
+#[test]
+fn test_mapped() {
+    assert_eq!(place_mapped(valid_order()), Ok(2));
+    let order = Order { product: "".to_string(), amount: 0 };
+    assert_eq!(place_mapped(order), Err(Error::Invalid(2)));
+}
@@ -0,0 +1,73 @@
+use vale::Validate;
+
+#[derive(Validate)]
+#[allow(dead_code)]
+struct Struct {
+    #[validate(gt(0))]
+    id: i32,
+    note: String,
+    #[validate(trim, len_lt(20))]
+    name: String,
+    #[validate(skip)]
+    cache: Vec<u8>,
+    #[validate(rename = "E-mail", email)]
+    email: String,
+}
+
+#[derive(Validate)]
+struct Generic<T> {
+    #[validate(required)]
+    value: Option<T>,
+}
+
+#[derive(Validate)]
+#[allow(dead_code)]
+enum Enum {
+    First {
+        #[validate(gt(0))]
+        amount: i32,
+    },
+    Second {
+        #[validate(lt(10))]
+        amount: i32,
+        #[validate(not_empty)]
+        label: String,
+    },
+    Third(#[validate(gt(0))] i32),
+}
+
+#[derive(Validate)]
+#[validate(with(|_: &mut Unvalidated| true))]
+#[allow(dead_code)]
+struct Unvalidated {
+    value: i32,
+}
+
+#[test]
+fn test_struct() {
+    assert_eq!(Struct::VALIDATED_FIELDS, ["id", "name", "email"]);
+    let mut s = Struct {
+        id: 1,
+        note: String::new(),
+        name: "name".to_string(),
+        cache: Vec::new(),
+        email: "luuk@example.com".to_string(),
+    };
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_generic() {
+    assert_eq!(Generic::<u8>::VALIDATED_FIELDS, ["value"]);
+}
+
+#[test]
+fn test_enum() {
+    assert_eq!(Enum::VALIDATED_FIELDS, ["amount", "label", "0"]);
+}
+
+#[test]
+fn test_no_validated_fields() {
+    assert!(Unvalidated::VALIDATED_FIELDS.is_empty());
+    assert!(Unvalidated { value: 0 }.validate().is_ok());
+}
@@ -0,0 +1,96 @@
+use vale::Validate;
+
+struct Account {
+    name: String,
+    password: String,
+}
+
+impl Validate for Account {
+    fn validate(&mut self) -> vale::Result {
+        self.validate_with_warnings().0
+    }
+
+    #[vale::ruleset]
+    fn validate_with_warnings(&mut self) -> (vale::Result, Vec<String>) {
+        vale::rule!(!self.name.is_empty(), "Empty name");
+        vale::rule!(self.password.len() >= 8, "Password too short");
+        vale::rule_warn!(self.password.len() >= 12, "Password of {} characters is weak", self.password.len());
+        vale::rule_warn!(self.password != self.name, "Password equals the name");
+    }
+}
+
+struct FailFast {
+    value: i32,
+}
+
+impl Validate for FailFast {
+    fn validate(&mut self) -> vale::Result {
+        self.validate_with_warnings().0
+    }
+
+    #[vale::ruleset(fail_fast)]
+    fn validate_with_warnings(&mut self) -> (vale::Result, Vec<String>) {
+        vale::rule_warn!(self.value < 100, "Large value");
+        vale::rule!(self.value > 0, "Nonpositive value");
+        vale::rule_warn!(self.value % 2 == 0, "Odd value");
+    }
+}
+
+#[derive(Validate)]
+struct Derived {
+    #[validate(gt(0))]
+    value: i32,
+}
+
+#[test]
+fn test_valid_with_warnings() {
+    let mut account = Account { name: "password".to_string(), password: "password".to_string() };
+    assert_eq!(account.validate(), Ok(()));
+    assert_eq!(
+        account.validate_with_warnings(),
+        (
+            Ok(()),
+            vec![
+                "Password of 8 characters is weak".to_string(),
+                "Password equals the name".to_string(),
+            ],
+        ),
+    );
+}
+
+#[test]
+fn test_errors_and_warnings() {
+    let mut account = Account { name: String::new(), password: "hunter2".to_string() };
+    assert_eq!(
+        account.validate_with_warnings(),
+        (
+            Err(vec!["Empty name".to_string(), "Password too short".to_string()]),
+            vec!["Password of 7 characters is weak".to_string()],
+        ),
+    );
+}
+
+#[test]
+fn test_fail_fast_keeps_warnings() {
+    let mut s = FailFast { value: -101 };
+    assert_eq!(
+        s.validate_with_warnings(),
+        (Err(vec!["Nonpositive value".to_string()]), Vec::<String>::new()),
+    );
+    let mut s = FailFast { value: 101 };
+    assert_eq!(
+        s.validate_with_warnings(),
+        (Ok(()), vec!["Large value".to_string(), "Odd value".to_string()]),
+    );
+}
+
+#[test]
+fn test_default_has_no_warnings() {
+    let mut s = Derived { value: 1 };
+    assert_eq!(s.validate_with_warnings(), (Ok(()), Vec::new()));
+    let mut s = Some(Derived { value: 0 });
+    assert_eq!(
+        s.validate_with_warnings(),
+        (Err(vec!["Failed to validate field `value`, value too low".to_string()]), Vec::new()),
+    );
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "warp")]
+
+use wrp::http::StatusCode;
+use wrp::Filter;
+
+#[derive(vale::Validate)]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct Struct {
+    #[validate(gt(10))]
+    value: u32,
+    #[validate(trim, len_lt(10), to_lower_case)]
+    transformer: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        value: 12,
+        transformer: "hello".to_string(),
+    }
+}
+
+fn filter() -> impl Filter<Extract = (impl wrp::Reply,), Error = wrp::Rejection> + Clone {
+    wrp::post()
+        .and(vale::warp::validated_json::<Struct>())
+        .map(|s: Struct| wrp::reply::json(&s))
+        .recover(vale::warp::recover)
+}
+
+#[tokio::test]
+async fn test_valid() {
+    let resp = wrp::test::request().method("POST").json(&valid_struct()).reply(&filter()).await;
+    assert_eq!(resp.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn test_transform() {
+    let mut s = valid_struct();
+    s.transformer = "   CAST ME   ".to_string();
+    let resp = wrp::test::request().method("POST").json(&s).reply(&filter()).await;
+    let resp: Struct = serde_json::from_slice(resp.body()).unwrap();
+    assert_eq!(resp.transformer, "cast me");
+}
+
+#[tokio::test]
+async fn test_invalid() {
+    let mut s = valid_struct();
+    s.value = 8;
+    let resp = wrp::test::request().method("POST").json(&s).reply(&filter()).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(resp.body(), r#"["Failed to validate field `value`, value too low"]"#);
+}
+
+#[tokio::test]
+async fn test_json_object_format() {
+    let filter = wrp::post()
+        .and(vale::warp::validated_json_with::<Struct>(vale::ResponseFormat::JsonObject))
+        .map(|s: Struct| wrp::reply::json(&s))
+        .recover(vale::warp::recover);
+    let mut s = valid_struct();
+    s.value = 8;
+    let resp = wrp::test::request().method("POST").json(&s).reply(&filter).await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(resp.headers()["content-type"], "application/json");
+    assert_eq!(resp.body(), r#"{"value":["Failed to validate field `value`, value too low"]}"#);
+}
+
+#[tokio::test]
+async fn test_rejection() {
+    let mut s = valid_struct();
+    s.value = 8;
+    let rejection = wrp::test::request()
+        .method("POST")
+        .json(&s)
+        .filter(&vale::warp::validated_json::<Struct>())
+        .await
+        .err()
+        .unwrap();
+    let rejection = rejection.find::<vale::warp::ValidationRejection>().unwrap();
+    assert_eq!(rejection.errors(), ["Failed to validate field `value`, value too low"]);
+}
+
+#[tokio::test]
+async fn test_malformed() {
+    let resp = wrp::test::request()
+        .method("POST")
+        .header("content-type", "application/json")
+        .body("{")
+        .reply(&filter())
+        .await;
+    assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,58 @@
+use vale::Validate;
+
+#[derive(Validate)]
+struct Address {
+    #[validate(len_gt(0))]
+    street: String,
+}
+
+#[derive(Validate)]
+struct Order {
+    needs_shipping: bool,
+    #[validate(when = "self.needs_shipping", required, nested)]
+    shipping_address: Option<Address>,
+    #[validate(gt(0))]
+    #[validate(lt(10), when = "!self.needs_shipping")]
+    quantity: u32,
+}
+
+#[test]
+fn test_guard_false() {
+    let mut order = Order { needs_shipping: false, shipping_address: None, quantity: 3 };
+    order.validate().unwrap();
+    order.shipping_address = Some(Address { street: String::new() });
+    order.validate().unwrap();
+    order.quantity = 0;
+    assert_eq!(
+        order.validate(),
+        Err(vec!["Failed to validate field `quantity`, value too low".to_string()]),
+    );
+}
+
+#[test]
+fn test_guard_true() {
+    let mut order = Order { needs_shipping: true, shipping_address: None, quantity: 30 };
+    assert_eq!(
+        order.validate(),
+        Err(vec!["Failed to validate field `shipping_address`, value is required".to_string()]),
+    );
+    order.shipping_address = Some(Address { street: String::new() });
+    assert_eq!(
+        order.validate(),
+        Err(vec![
+            "shipping_address: Failed to validate field `street`, value too short".to_string(),
+        ]),
+    );
+    order.shipping_address = Some(Address { street: "Main Street".to_string() });
+    order.validate().unwrap();
+}
+
+#[test]
+fn test_guard_fields() {
+    let mut order = Order { needs_shipping: true, shipping_address: None, quantity: 1 };
+    let errors = order.validate_fields().unwrap_err();
+    assert_eq!(
+        errors.get("shipping_address"),
+        Some(&["Failed to validate field `shipping_address`, value is required".to_string()][..]),
+    );
+}
@@ -0,0 +1,65 @@
+use vale::Validate;
+
+mod checks {
+    pub fn is_even(num: &mut i32) -> bool {
+        *num % 2 == 0
+    }
+
+    pub fn is_lowercase(s: &mut String) -> Result<(), String> {
+        if s.chars().any(char::is_uppercase) {
+            return Err(format!("`{}` contains uppercase characters", s));
+        }
+        Ok(())
+    }
+}
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(with(checks::is_even))]
+    even: i32,
+    #[validate(with(crate::checks::is_even), with(|x: &mut i32| *x > 0))]
+    positive_even: i32,
+    #[validate(try_with(checks::is_lowercase))]
+    name: String,
+    #[validate(with(|s: &mut String| { s.push('!'); true }), len_lt(5))]
+    shout: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct { even: 2, positive_even: 4, name: "luuk".to_string(), shout: "hey".to_string() }
+}
+
+#[test]
+fn test_valid() {
+    let mut s = valid_struct();
+    s.validate().unwrap();
+    assert_eq!(s.shout, "hey!");
+}
+
+#[test]
+fn test_path() {
+    let mut s = valid_struct();
+    s.even = 3;
+    s.name = "Luuk".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `even`, value did not pass test".to_string(),
+            "`Luuk` contains uppercase characters".to_string(),
+        ]),
+    );
+}
+
+#[test]
+fn test_closure() {
+    let mut s = valid_struct();
+    s.positive_even = -2;
+    s.shout = "hello".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `positive_even`, value did not pass test".to_string(),
+            "Failed to validate field `shout`, value too long".to_string(),
+        ]),
+    );
+}
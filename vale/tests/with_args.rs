@@ -0,0 +1,54 @@
+use vale::Validate;
+
+fn in_range(value: &mut i32, min: i32, max: i32) -> bool {
+    (min..=max).contains(value)
+}
+
+fn has_prefix(value: &mut str, prefix: &str) -> bool {
+    value.starts_with(prefix)
+}
+
+#[derive(Validate)]
+struct Struct {
+    #[validate(with_args(in_range, 1, 10))]
+    rating: i32,
+    #[validate(with_args(in_range, -(self.limit), self.limit))]
+    offset: i32,
+    limit: i32,
+    #[validate(with_args(has_prefix, "ID-"), with_args(|s: &mut String| !s.is_empty()))]
+    id: String,
+}
+
+fn valid_struct() -> Struct {
+    Struct {
+        rating: 5,
+        offset: -3,
+        limit: 5,
+        id: "ID-12".to_string(),
+    }
+}
+
+#[test]
+fn test_valid() {
+    valid_struct().validate().unwrap();
+    let mut s = valid_struct();
+    s.rating = 10;
+    s.offset = 5;
+    s.validate().unwrap();
+}
+
+#[test]
+fn test_out_of_range() {
+    let mut s = valid_struct();
+    s.rating = 11;
+    s.offset = -6;
+    s.id = "12".to_string();
+    assert_eq!(
+        s.validate(),
+        Err(vec![
+            "Failed to validate field `rating`, value did not pass test".to_string(),
+            "Failed to validate field `offset`, value did not pass test".to_string(),
+            "Failed to validate field `id`, value did not pass test".to_string(),
+        ]),
+    );
+}